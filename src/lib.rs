@@ -0,0 +1,9 @@
+//! Implementation of the Range Minimum Query algorithms presented in [1].
+//!
+//! [1] M.A. Bender, M. Farach-Colton:
+//!     The LCA Problem Revisited.
+//!     LATIN 2000, LNCS 1776, 88-94, 2000.
+
+pub mod log;
+pub mod rmq;
+pub mod tree;
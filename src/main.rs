@@ -6,12 +6,14 @@
 
 use std::fmt::Write;
 
-use crate::rmq::{Naive, NoPreprocessing, Reference, SegmentTree, SparseTable};
+use rmq_rs::rmq::{
+    AppendOnly, BitAnd, BitOr, BlockBitmask, ByKey, DisjointSparseTable, FischerHeun,
+    ImplicitTreap, LazySegmentTree, LcaPlusMinus, Leftmost, Max, Min, Naive, Natural,
+    NoPreprocessing, PersistentSegmentTree, PlusMinus, Reference, Reverse, SegmentTree,
+    SegmentTreeBeats, SegmentTreeCache, SparseTable, SqrtDecomposition, Then,
+};
 
-mod log;
-mod rmq;
 mod rmq_test;
-mod tree;
 
 /// Converts the given time (in milliseconds) into the a string using appropriate unit.
 fn print_time(time: i64) -> String {
@@ -29,25 +31,25 @@ fn print_time(time: i64) -> String {
     } else if time <= MINUTE {
         let mut d_secs = (time + DEC_SEC - 1) / DEC_SEC;
         let secs = d_secs / 10;
-        d_secs = d_secs % 10;
+        d_secs %= 10;
 
         write!(time_str, "{secs: >2}.{d_secs} s").unwrap();
     } else if time <= HOUR {
         let mut secs = (time + SECOND - 1) / SECOND;
         let mins = secs / 60;
-        secs = secs % 60;
+        secs %= 60;
 
         write!(time_str, "{mins: >2} min {secs: >2} s").unwrap();
     } else if time <= DAY {
         let mut mins = (time + MINUTE - 1) / MINUTE;
         let hours = mins / 60;
-        mins = mins % 60;
+        mins %= 60;
 
         write!(time_str, "{hours: >2} h {mins: >2} min").unwrap();
     } else {
         let mut hours = (time + HOUR - 1) / HOUR;
         let days = hours / 24;
-        hours = hours % 24;
+        hours %= 24;
 
         write!(time_str, "{days: >2} d {hours: >2} h").unwrap();
     }
@@ -58,6 +60,8 @@ fn print_time(time: i64) -> String {
 fn main() {
     const DATA_SIZE: usize = 20000;
     const QUERIES: usize = 1000000;
+    // Verifiers that scan each queried range run fewer queries.
+    const SCAN_QUERIES: usize = 10000;
     const SEED: u64 = 19082017;
 
     println!("   Size: {}", DATA_SIZE);
@@ -116,6 +120,77 @@ fn main() {
         // Verify correctness.
         type VerifyAlgo = Naive<rmq_test::Number>;
         let correct = rmq_test::verify_algorithms::<RmqAlgo, VerifyAlgo>(DATA_SIZE, QUERIES, SEED);
+        let correct = correct && rmq_test::verify_updates::<RmqAlgo>(DATA_SIZE, QUERIES, SEED);
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** Lazy Segment Tree ***");
+    {
+        type RmqAlgo = LazySegmentTree<rmq_test::Number>;
+        let time_pair = rmq_test::get_runtime::<RmqAlgo>(DATA_SIZE, QUERIES, SEED);
+
+        println!("P: {}", print_time(time_pair.0 - ref_time.0));
+        println!("Q: {}", print_time(time_pair.1 - ref_time.1));
+
+        // Verify correctness.
+        type VerifyAlgo = SegmentTree<rmq_test::Number>;
+        let correct = rmq_test::verify_algorithms::<RmqAlgo, VerifyAlgo>(DATA_SIZE, QUERIES, SEED);
+        let correct = correct && rmq_test::verify_updates::<RmqAlgo>(DATA_SIZE, QUERIES, SEED);
+        let correct = correct && rmq_test::verify_range_updates(DATA_SIZE, QUERIES, SEED);
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** Segment Tree Beats ***");
+    {
+        type RmqAlgo = SegmentTreeBeats<rmq_test::Number>;
+        let time_pair = rmq_test::get_runtime::<RmqAlgo>(DATA_SIZE, QUERIES, SEED);
+
+        println!("P: {}", print_time(time_pair.0 - ref_time.0));
+        println!("Q: {}", print_time(time_pair.1 - ref_time.1));
+
+        // Verify correctness.
+        type VerifyAlgo = SegmentTree<rmq_test::Number>;
+        let correct = rmq_test::verify_algorithms::<RmqAlgo, VerifyAlgo>(DATA_SIZE, QUERIES, SEED);
+        let correct = correct && rmq_test::verify_updates::<RmqAlgo>(DATA_SIZE, QUERIES, SEED);
+        let correct = correct && rmq_test::verify_range_clamps(DATA_SIZE, QUERIES, SEED);
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** Persistent Segment Tree ***");
+    {
+        type RmqAlgo = PersistentSegmentTree<rmq_test::Number>;
+        let time_pair = rmq_test::get_runtime::<RmqAlgo>(DATA_SIZE, QUERIES, SEED);
+
+        println!("P: {}", print_time(time_pair.0 - ref_time.0));
+        println!("Q: {}", print_time(time_pair.1 - ref_time.1));
+
+        // Verify correctness.
+        type VerifyAlgo = NoPreprocessing<rmq_test::Number>;
+        let correct = rmq_test::verify_algorithms::<RmqAlgo, VerifyAlgo>(DATA_SIZE, QUERIES, SEED);
+        let correct = correct && rmq_test::verify_versions::<VerifyAlgo>(DATA_SIZE, QUERIES, SEED);
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** Implicit Treap ***");
+    {
+        type RmqAlgo = ImplicitTreap<rmq_test::Number>;
+        let time_pair = rmq_test::get_runtime::<RmqAlgo>(DATA_SIZE, QUERIES, SEED);
+
+        println!("P: {}", print_time(time_pair.0 - ref_time.0));
+        println!("Q: {}", print_time(time_pair.1 - ref_time.1));
+
+        // Verify correctness.
+        type VerifyAlgo = SegmentTree<rmq_test::Number>;
+        let correct = rmq_test::verify_algorithms::<RmqAlgo, VerifyAlgo>(DATA_SIZE, QUERIES, SEED);
+        let correct = correct && rmq_test::verify_sequence_updates(DATA_SIZE, QUERIES, SEED);
 
         println!("C: {}", if correct { "Yes" } else { "No" });
         println!();
@@ -123,15 +198,32 @@ fn main() {
 
     println!("*** Segment Tree Cache ***");
     {
-        // ToDo: Implement and run algorithm.
-        let time_pair = (0, 0);
+        type RmqAlgo = SegmentTreeCache<rmq_test::Number>;
+        let time_pair = rmq_test::get_runtime::<RmqAlgo>(DATA_SIZE, QUERIES, SEED);
 
         println!("P: {}", print_time(time_pair.0 - ref_time.0));
         println!("Q: {}", print_time(time_pair.1 - ref_time.1));
 
         // Verify correctness.
-        // ToDo: Implement and run algorithm.
-        let correct = true;
+        type VerifyAlgo = SegmentTree<rmq_test::Number>;
+        let correct = rmq_test::verify_algorithms::<RmqAlgo, VerifyAlgo>(DATA_SIZE, QUERIES, SEED);
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** Sqrt Decomposition ***");
+    {
+        type RmqAlgo = SqrtDecomposition<rmq_test::Number>;
+        let time_pair = rmq_test::get_runtime::<RmqAlgo>(DATA_SIZE, QUERIES, SEED);
+
+        println!("P: {}", print_time(time_pair.0 - ref_time.0));
+        println!("Q: {}", print_time(time_pair.1 - ref_time.1));
+
+        // Verify correctness.
+        type VerifyAlgo = SegmentTree<rmq_test::Number>;
+        let correct = rmq_test::verify_algorithms::<RmqAlgo, VerifyAlgo>(DATA_SIZE, QUERIES, SEED);
+        let correct = correct && rmq_test::verify_updates::<RmqAlgo>(DATA_SIZE, QUERIES, SEED);
 
         println!("C: {}", if correct { "Yes" } else { "No" });
         println!();
@@ -170,15 +262,294 @@ fn main() {
 
     println!("*** RMQ via +-1 LCA ***");
     {
-        // ToDo: Implement and run algorithm.
-        let time_pair = (0, 0);
+        type RmqAlgo = LcaPlusMinus<rmq_test::Number>;
+        let time_pair = rmq_test::get_runtime::<RmqAlgo>(DATA_SIZE, QUERIES, SEED);
 
         println!("P: {}", print_time(time_pair.0 - ref_time.0));
         println!("Q: {}", print_time(time_pair.1 - ref_time.1));
 
         // Verify correctness.
-        // ToDo: Implement and run algorithm.
-        let correct = true;
+        type VerifyAlgo = SparseTable<rmq_test::Number>;
+        let correct = rmq_test::verify_algorithms::<RmqAlgo, VerifyAlgo>(DATA_SIZE, QUERIES, SEED)
+            // Short data uses blocks of size 1.
+            && (2..32).all(|n| rmq_test::verify_algorithms::<RmqAlgo, VerifyAlgo>(n, 1000, SEED));
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** Fischer Heun ***");
+    {
+        type RmqAlgo = FischerHeun<rmq_test::Number>;
+        let time_pair = rmq_test::get_runtime::<RmqAlgo>(DATA_SIZE, QUERIES, SEED);
+
+        println!("P: {}", print_time(time_pair.0 - ref_time.0));
+        println!("Q: {}", print_time(time_pair.1 - ref_time.1));
+
+        // Verify correctness.
+        type VerifyAlgo = SparseTable<rmq_test::Number>;
+        let correct = rmq_test::verify_algorithms::<RmqAlgo, VerifyAlgo>(DATA_SIZE, QUERIES, SEED);
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** Block Bitmask ***");
+    {
+        type RmqAlgo = BlockBitmask<rmq_test::Number>;
+        let time_pair = rmq_test::get_runtime::<RmqAlgo>(DATA_SIZE, QUERIES, SEED);
+
+        println!("P: {}", print_time(time_pair.0 - ref_time.0));
+        println!("Q: {}", print_time(time_pair.1 - ref_time.1));
+
+        // Verify correctness.
+        type VerifyAlgo = SparseTable<rmq_test::Number>;
+        let correct = rmq_test::verify_algorithms::<RmqAlgo, VerifyAlgo>(DATA_SIZE, QUERIES, SEED);
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** Append Only ***");
+    {
+        type RmqAlgo = AppendOnly<rmq_test::Number>;
+        let time_pair = rmq_test::get_runtime::<RmqAlgo>(DATA_SIZE, QUERIES, SEED);
+
+        println!("P: {}", print_time(time_pair.0 - ref_time.0));
+        println!("Q: {}", print_time(time_pair.1 - ref_time.1));
+
+        // Verify correctness.
+        type VerifyAlgo = SparseTable<rmq_test::Number>;
+        let correct = rmq_test::verify_algorithms::<RmqAlgo, VerifyAlgo>(DATA_SIZE, QUERIES, SEED);
+        let correct = correct && rmq_test::verify_appends::<VerifyAlgo>(DATA_SIZE, QUERIES, SEED);
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** Cartesian Tree ***");
+    {
+        // Verify correctness.
+        type VerifyAlgo = NoPreprocessing<rmq_test::Number>;
+        let correct = rmq_test::verify_cartesian_tree::<VerifyAlgo>(DATA_SIZE, QUERIES, SEED);
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** Top k ***");
+    {
+        // Verify correctness.
+        type VerifyAlgo = SparseTable<rmq_test::Number>;
+        let correct = rmq_test::verify_top_k::<VerifyAlgo>(DATA_SIZE, SCAN_QUERIES, SEED);
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** All Minima ***");
+    {
+        // Verify correctness.
+        type VerifyAlgo = SparseTable<rmq_test::Number>;
+        let correct = rmq_test::verify_all_minima::<VerifyAlgo>(DATA_SIZE, SCAN_QUERIES, SEED);
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** Document Listing ***");
+    {
+        // Verify correctness.
+        type RmqAlgo = SparseTable<usize>;
+        let correct = rmq_test::verify_document_listing::<RmqAlgo>(DATA_SIZE, SCAN_QUERIES, SEED);
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** Threshold Search ***");
+    {
+        // Verify correctness.
+        type VerifyAlgo = SparseTable<rmq_test::Number>;
+        let correct =
+            rmq_test::verify_threshold_search::<VerifyAlgo>(DATA_SIZE, SCAN_QUERIES, SEED);
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** Offline (Union-Find) ***");
+    {
+        let time_pair = rmq_test::get_offline_runtime(DATA_SIZE, QUERIES, SEED);
+
+        // Queries are generated before measuring; no reference to subtract.
+        println!("P: {}", print_time(time_pair.0));
+        println!("Q: {}", print_time(time_pair.1));
+
+        // Verify correctness.
+        type VerifyAlgo = NoPreprocessing<rmq_test::Number>;
+        let correct = rmq_test::verify_offline::<VerifyAlgo>(DATA_SIZE, QUERIES, SEED);
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** Sliding Window ***");
+    {
+        // Verify correctness.
+        type VerifyAlgo = SparseTable<rmq_test::Number>;
+        let correct = rmq_test::verify_sliding_window::<VerifyAlgo>(DATA_SIZE, QUERIES, SEED);
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** Circular Ranges ***");
+    {
+        // Verify correctness.
+        type NoPrepAlgo = NoPreprocessing<rmq_test::Number>;
+        type NaiveAlgo = Naive<rmq_test::Number>;
+
+        let correct = rmq_test::verify_circular::<NoPrepAlgo>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_circular::<NaiveAlgo>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_circular_plus_minus(DATA_SIZE, QUERIES, SEED);
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** Range Maximum ***");
+    {
+        // Verify correctness.
+        type VerifyAlgo = NoPreprocessing<rmq_test::Number, Reverse>;
+        type SparseMax = SparseTable<rmq_test::Number, Max>;
+        type SegmentMax = SegmentTree<rmq_test::Number, Max>;
+        type FischerHeunMax = FischerHeun<rmq_test::Number, Reverse>;
+        type LcaMax = LcaPlusMinus<rmq_test::Number, Reverse>;
+
+        let correct =
+            rmq_test::verify_algorithms::<SparseMax, VerifyAlgo>(DATA_SIZE, QUERIES, SEED)
+                && rmq_test::verify_algorithms::<SegmentMax, VerifyAlgo>(DATA_SIZE, QUERIES, SEED)
+                && rmq_test::verify_algorithms::<FischerHeunMax, VerifyAlgo>(
+                    DATA_SIZE, QUERIES, SEED,
+                )
+                && rmq_test::verify_algorithms::<LcaMax, VerifyAlgo>(DATA_SIZE, QUERIES, SEED);
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** Custom Orders ***");
+    {
+        // Verify correctness.
+        type Record = rmq_test::Record;
+        type KeyOrder = ByKey<rmq_test::RecordKey>;
+        type LexOrder = Then<KeyOrder, ByKey<rmq_test::RecordTie>>;
+
+        type SparseKey = SparseTable<Record, Min<KeyOrder>>;
+        type SparseLex = SparseTable<Record, Min<LexOrder>>;
+        type SegmentKey = SegmentTree<Record, Min<KeyOrder>>;
+        type SegmentLex = SegmentTree<Record, Min<LexOrder>>;
+        type FischerHeunKey = FischerHeun<Record, KeyOrder>;
+        type FischerHeunLex = FischerHeun<Record, LexOrder>;
+        type LcaKey = LcaPlusMinus<Record, KeyOrder>;
+        type LcaLex = LcaPlusMinus<Record, LexOrder>;
+
+        let correct = rmq_test::verify_key_orders::<SparseKey, SparseLex>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_key_orders::<SegmentKey, SegmentLex>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_key_orders::<FischerHeunKey, FischerHeunLex>(
+                DATA_SIZE, QUERIES, SEED,
+            )
+            && rmq_test::verify_key_orders::<LcaKey, LcaLex>(DATA_SIZE, QUERIES, SEED);
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** Tie Breaking ***");
+    {
+        // Verify correctness.
+        type VerifyRight = NoPreprocessing<rmq_test::Number>;
+        type VerifyLeft = NoPreprocessing<rmq_test::Number, Leftmost>;
+
+        type NaiveLeft = Naive<rmq_test::Number, Min<Leftmost>>;
+        type SegmentLeft = SegmentTree<rmq_test::Number, Min<Leftmost>>;
+        type SegmentCacheLeft = SegmentTreeCache<rmq_test::Number, Leftmost>;
+        type SparseLeft = SparseTable<rmq_test::Number, Min<Leftmost>>;
+        type DisjointRight = DisjointSparseTable<rmq_test::Number, Min>;
+        type DisjointLeft = DisjointSparseTable<rmq_test::Number, Min<Leftmost>>;
+        type SqrtLeft = SqrtDecomposition<rmq_test::Number, Leftmost>;
+        type BitmaskLeft = BlockBitmask<rmq_test::Number, Leftmost>;
+        type PlusMinusLeft = PlusMinus<rmq_test::Number, Leftmost>;
+        type FischerHeunLeft = FischerHeun<rmq_test::Number, Leftmost>;
+        type LcaLeft = LcaPlusMinus<rmq_test::Number, Leftmost>;
+
+        let correct = rmq_test::verify_ties::<Naive<_>, VerifyRight>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_ties::<SegmentTree<_>, VerifyRight>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_ties::<SegmentTreeCache<_>, VerifyRight>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_ties::<SparseTable<_>, VerifyRight>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_ties::<DisjointRight, VerifyRight>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_ties::<SqrtDecomposition<_>, VerifyRight>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_ties::<BlockBitmask<_>, VerifyRight>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_plus_minus_ties::<PlusMinus<_>, VerifyRight>(
+                DATA_SIZE, QUERIES, SEED,
+            )
+            && rmq_test::verify_ties::<FischerHeun<_>, VerifyRight>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_ties::<LcaPlusMinus<_>, VerifyRight>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_offline_ties::<VerifyRight, Natural>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_ties::<NaiveLeft, VerifyLeft>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_ties::<SegmentLeft, VerifyLeft>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_ties::<SegmentCacheLeft, VerifyLeft>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_ties::<SparseLeft, VerifyLeft>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_ties::<DisjointLeft, VerifyLeft>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_ties::<SqrtLeft, VerifyLeft>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_ties::<BitmaskLeft, VerifyLeft>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_plus_minus_ties::<PlusMinusLeft, VerifyLeft>(
+                DATA_SIZE, QUERIES, SEED,
+            )
+            && rmq_test::verify_ties::<FischerHeunLeft, VerifyLeft>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_ties::<LcaLeft, VerifyLeft>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_offline_ties::<VerifyLeft, Leftmost>(DATA_SIZE, QUERIES, SEED);
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** Range Aggregates ***");
+    {
+        // Verify correctness.
+        let correct = rmq_test::verify_sparse_table_fold::<Max>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_sparse_table_fold::<BitAnd>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_sparse_table_fold::<BitOr>(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_gcd_lcm(DATA_SIZE, QUERIES, SEED)
+            && rmq_test::verify_segment_tree_fold(DATA_SIZE, QUERIES, SEED);
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** 2D Range Minimum ***");
+    {
+        // Verify correctness.
+        let correct = rmq_test::verify_rectangles(DATA_SIZE, SCAN_QUERIES, SEED);
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** Second Minimum ***");
+    {
+        // Verify correctness.
+        let correct = rmq_test::verify_second_minimum(DATA_SIZE, QUERIES, SEED);
+
+        println!("C: {}", if correct { "Yes" } else { "No" });
+        println!();
+    }
+
+    println!("*** Disjoint Sparse Table ***");
+    {
+        // Verify correctness.
+        let correct = rmq_test::verify_disjoint_sparse_table(DATA_SIZE, QUERIES, SEED);
 
         println!("C: {}", if correct { "Yes" } else { "No" });
         println!();
@@ -191,8 +562,8 @@ fn main() {
 
     println!("*** Reference ***");
     {
-        // ToDo: Implement and run algorithm.
-        let time_pair = (0, 0);
+        type RmqAlgo = Reference<usize>;
+        let time_pair = rmq_test::get_ancestor_runtime::<RmqAlgo>(DATA_SIZE, QUERIES, SEED);
 
         println!("P: {}", print_time(time_pair.0));
         println!("Q: {}", print_time(time_pair.1));
@@ -203,8 +574,8 @@ fn main() {
 
     println!("*** Segment Tree Cache ***");
     {
-        // ToDo: Implement and run algorithm.
-        let time_pair = (0, 0);
+        type RmqAlgo = SegmentTreeCache<usize>;
+        let time_pair = rmq_test::get_ancestor_runtime::<RmqAlgo>(DATA_SIZE, QUERIES, SEED);
 
         println!("P: {}", print_time(time_pair.0 - ref_time.0));
         println!("Q: {}", print_time(time_pair.1 - ref_time.1));
@@ -213,8 +584,8 @@ fn main() {
 
     println!("*** Sparse Table ***");
     {
-        // ToDo: Implement and run algorithm.
-        let time_pair = (0, 0);
+        type RmqAlgo = SparseTable<usize>;
+        let time_pair = rmq_test::get_ancestor_runtime::<RmqAlgo>(DATA_SIZE, QUERIES, SEED);
 
         println!("P: {}", print_time(time_pair.0 - ref_time.0));
         println!("Q: {}", print_time(time_pair.1 - ref_time.1));
@@ -223,8 +594,8 @@ fn main() {
 
     println!("*** Plus Minus 1 ***");
     {
-        // ToDo: Implement and run algorithm.
-        let time_pair = (0, 0);
+        type RmqAlgo = PlusMinus<usize>;
+        let time_pair = rmq_test::get_ancestor_runtime::<RmqAlgo>(DATA_SIZE, QUERIES, SEED);
 
         println!("P: {}", print_time(time_pair.0 - ref_time.0));
         println!("Q: {}", print_time(time_pair.1 - ref_time.1));
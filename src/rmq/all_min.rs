@@ -0,0 +1,83 @@
+use super::{Natural, Order, Rmq};
+
+/// Represents a step of an in-order traversal of ranges split at their
+/// minimum.
+enum Step {
+    /// Search for minima in the range [l, r].
+    Range(usize, usize),
+
+    /// Report the given index.
+    Report(usize),
+}
+
+/// Determines the indices of all entries in the range [i, j] which are equal
+/// to the minimum of that range, using the given RMQ over the given data. The
+/// indices are sorted in increasing order.
+///
+/// The RMQ finds one minimum. All others are in the ranges left and right of
+/// it; searching them is stopped as soon as their minimum is larger.
+/// Behaviour is undefined if the given range is invalid.
+/// Runtime: O(occ) queries
+pub fn all_min_positions<T, R>(rmq: &R, data: &[T], i: usize, j: usize) -> Vec<usize>
+where
+    T: PartialOrd,
+    R: Rmq<T>,
+{
+    all_min_positions_by::<T, Natural, R>(rmq, data, i, j)
+}
+
+/// Determines the indices of all minima in the range [i, j] with respect to
+/// the given order. That is, all entries to which no entry of the range is
+/// smaller.
+/// See `all_min_positions()`.
+pub fn all_min_positions_by<T, O, R>(rmq: &R, data: &[T], i: usize, j: usize) -> Vec<usize>
+where
+    O: Order<T>,
+    R: Rmq<T>,
+{
+    let min_val = &data[rmq.query(i, j)];
+
+    // Stop searching a range if its minimum is larger.
+    report_minima(rmq, i, j, |idx| !O::less(min_val, &data[idx]))
+}
+
+/// Splits the range [i, j] at its minimum and then the ranges left and right
+/// of it, and so on. Reports the minimum of each such range for which the
+/// given predicate holds; otherwise, the range is not searched further. The
+/// indices are sorted in increasing order.
+pub(super) fn report_minima<T, R, P>(rmq: &R, i: usize, j: usize, pred: P) -> Vec<usize>
+where
+    R: Rmq<T>,
+    P: Fn(usize) -> bool,
+{
+    let mut result = Vec::new();
+
+    // Traverse ranges in order (left range, minimum, right range) such that
+    // indices are found in increasing order. Recursion could get too deep if
+    // there are many reported entries.
+    let mut stack = vec![Step::Range(i, j)];
+
+    while let Some(step) = stack.pop() {
+        match step {
+            Step::Report(idx) => result.push(idx),
+
+            Step::Range(l, r) => {
+                let idx = rmq.query(l, r);
+
+                if !pred(idx) {
+                    continue;
+                }
+
+                if idx < r {
+                    stack.push(Step::Range(idx + 1, r));
+                }
+                stack.push(Step::Report(idx));
+                if l < idx {
+                    stack.push(Step::Range(l, idx - 1));
+                }
+            }
+        }
+    }
+
+    result
+}
@@ -0,0 +1,150 @@
+use std::{marker::PhantomData, rc::Rc};
+
+use crate::log::log_f;
+
+use super::{
+    block_bitmask::{block_query, in_block_min, push_stack, BLOCK_DIV, BLOCK_MOD},
+    min_index, Natural, Order, Rmq,
+};
+
+/// Represents a RMQ algorithm that allows to append entries after
+/// pre-processing.
+/// Like BlockBitmask, it splits the data into blocks of 64 entries and stores
+/// the monotonic stack of each entry's block as a 64 bit mask. Since a stack
+/// only depends on the entries before it, appending an entry only computes its
+/// own mask. Once a block is complete, one column is added to the sparse table
+/// over the minima of blocks.
+/// Runtime: O(n) | O(1), push amortized O(1 + (log n) / 64)
+pub struct AppendOnly<T, O: Order<T> = Natural> {
+    /// A copy of the data, which is extended by pushes.
+    data: Vec<T>,
+
+    /// The stack of each entry. Bit k is set if the k-th entry of the block is
+    /// on the stack.
+    masks: Vec<u64>,
+
+    /// Sparse table over all complete blocks. table[k][b] is the index of the
+    /// minimum in blocks b to b + 2^k - 1. Unlike SparseTable, it grows with
+    /// the data.
+    table: Vec<Vec<usize>>,
+
+    phantom_data: PhantomData<O>,
+}
+
+impl<T: Clone, O: Order<T>> AppendOnly<T, O> {
+    /// Constructor.
+    pub fn new(data: Rc<[T]>) -> Self {
+        Self {
+            data: data.to_vec(),
+            masks: Vec::new(),
+            table: Vec::new(),
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+impl<T, O: Order<T>> AppendOnly<T, O> {
+    /// Pre-processes the data to allow queries.
+    pub fn process_data(&mut self) {
+        self.masks.clear();
+        self.masks.reserve(self.data.len());
+        self.table.clear();
+
+        for idx in 0..self.data.len() {
+            self.process_entry(idx);
+        }
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Determines if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Appends the given value to the data.
+    /// Behaviour is undefined if pre-processing has not been done.
+    pub fn push(&mut self, value: T) {
+        self.data.push(value);
+        self.process_entry(self.data.len() - 1);
+    }
+
+    /// Performs a query on the given range.
+    /// Returns the index of the minimum in that range.
+    /// Behaviour is undefined if the given range is invalid or pre-processing
+    /// has not been done.
+    pub fn query(&self, i: usize, j: usize) -> usize {
+        // All blocks between i and j are complete.
+        block_query::<T, O>(&self.data, &self.masks, i, j, |l_b, r_b| {
+            let k = log_f(r_b - l_b);
+
+            min_index::<T, O>(
+                &self.data,
+                self.table[k][l_b],
+                self.table[k][r_b + 1 - (1 << k)],
+            )
+        })
+    }
+
+    /// Computes the stack of the entry at the given index. If its block is
+    /// complete afterwards, extends the sparse table.
+    /// Requires that all entries before it have been processed.
+    fn process_entry(&mut self, idx: usize) {
+        // Continue the stack of the previous entry in the block.
+        let prev = if idx & BLOCK_MOD == 0 {
+            0
+        } else {
+            self.masks[idx - 1]
+        };
+        self.masks.push(push_stack::<T, O>(&self.data, prev, idx));
+
+        if idx & BLOCK_MOD == BLOCK_MOD {
+            self.complete_block(idx >> BLOCK_DIV);
+        }
+    }
+
+    /// Adds the ranges of blocks ending with the given (complete) block to the
+    /// sparse table.
+    fn complete_block(&mut self, b: usize) {
+        let b_sta = b << BLOCK_DIV;
+        let b_min = in_block_min(&self.masks, b_sta, b_sta + BLOCK_MOD);
+
+        if self.table.is_empty() {
+            self.table.push(Vec::new());
+        }
+        self.table[0].push(b_min);
+
+        // Range [b + 1 - 2^k, b] combines M[k - 1, b + 1 - 2^k] and
+        // M[k - 1, b + 1 - 2^{k - 1}].
+        let mut k = 1;
+        while (1 << k) <= b + 1 {
+            if self.table.len() == k {
+                self.table.push(Vec::new());
+            }
+
+            let l_res = self.table[k - 1][b + 1 - (1 << k)];
+            let r_res = self.table[k - 1][b + 1 - (1 << (k - 1))];
+            let res = min_index::<T, O>(&self.data, l_res, r_res);
+
+            self.table[k].push(res);
+            k += 1;
+        }
+    }
+}
+
+impl<T: Clone, O: Order<T>> Rmq<T> for AppendOnly<T, O> {
+    fn new(data: Rc<[T]>) -> Self {
+        Self::new(data)
+    }
+
+    fn process_data(&mut self) {
+        self.process_data();
+    }
+
+    fn query(&self, i: usize, j: usize) -> usize {
+        self.query(i, j)
+    }
+}
@@ -0,0 +1,160 @@
+use std::rc::Rc;
+
+use super::{min_index, Min, Natural, Order, Rmq, SparseTable};
+
+/// The number of entries in a block, i.e., the number of bits in a mask.
+const BLOCK_SIZE: usize = 64;
+
+/// log_2(BLOCK_SIZE) to allow shifts.
+pub(super) const BLOCK_DIV: usize = 6;
+
+/// BLOCK_SIZE - 1 to allow bitwise modulo operations.
+pub(super) const BLOCK_MOD: usize = BLOCK_SIZE - 1;
+
+/// Represents a RMQ algorithm that splits the data into blocks of 64 entries.
+/// For each entry, it stores the monotonic stack of its block (i.e., all
+/// entries up to it with no smaller entry to their right) as a 64 bit mask.
+/// The minimum in a range [i, j] of a block is then the first entry of j's
+/// stack not before i. Queries over multiple blocks use a sparse table over
+/// the minima of blocks.
+/// Runtime: O(n) | O(1)
+pub struct BlockBitmask<T, O: Order<T> = Natural> {
+    data: Rc<[T]>,
+
+    /// The stack of each entry. Bit k is set if the k-th entry of the block is
+    /// on the stack.
+    masks: Vec<u64>,
+
+    /// The index of each block's minimum in the original data.
+    block_min_idx: Vec<usize>,
+
+    /// A RMQ to find the minimum block.
+    table_rmq: SparseTable<T, Min<O>>,
+}
+
+impl<T: Clone, O: Order<T>> Rmq<T> for BlockBitmask<T, O> {
+    fn new(data: Rc<[T]>) -> Self {
+        Self {
+            data,
+            masks: Vec::new(),
+            block_min_idx: Vec::new(),
+            table_rmq: SparseTable::new(Rc::from(vec![].into_boxed_slice())),
+        }
+    }
+
+    fn process_data(&mut self) {
+        let data: &[T] = &self.data;
+        let n = data.len();
+
+        // ceil(x / y) = floor((x - 1) / y) + 1
+        let block_count = ((n - 1) >> BLOCK_DIV) + 1;
+
+        // --- Compute stacks. ---
+
+        self.masks.clear();
+        self.masks.reserve(n);
+
+        for i in 0..n {
+            let prev = if i & BLOCK_MOD == 0 {
+                0
+            } else {
+                self.masks[i - 1]
+            };
+            self.masks.push(push_stack::<T, O>(data, prev, i));
+        }
+
+        // --- Determine minimum in each block. ---
+
+        // The minimum of each block.
+        let mut block_min_val = Vec::with_capacity(block_count);
+
+        self.block_min_idx.clear();
+        self.block_min_idx.reserve(block_count);
+
+        for b in 0..block_count {
+            let b_sta = b << BLOCK_DIV;
+            let b_end = std::cmp::min(b_sta + BLOCK_SIZE, n);
+
+            let min_idx = in_block_min(&self.masks, b_sta, b_end - 1);
+
+            block_min_val.push(data[min_idx].clone());
+            self.block_min_idx.push(min_idx);
+        }
+
+        // Create RMQ over blocks.
+        self.table_rmq = SparseTable::new(Rc::from(block_min_val.into_boxed_slice()));
+        self.table_rmq.process_data();
+    }
+
+    fn query(&self, i: usize, j: usize) -> usize {
+        block_query::<T, O>(&self.data, &self.masks, i, j, |l_b, r_b| {
+            self.block_min_idx[self.table_rmq.query(l_b, r_b)]
+        })
+    }
+}
+
+/// Determines the stack of the entry at the given index from the stack of the
+/// entry before it in the same block (0 if it is the first one).
+/// Removes all entries that are not preferred over data[idx] as minimum (i.e.,
+/// larger ones and, depending on the tie-breaking policy, equal ones).
+pub(super) fn push_stack<T, O: Order<T>>(data: &[T], prev: u64, idx: usize) -> u64 {
+    let b_sta = idx & !BLOCK_MOD;
+    let mut stack = prev;
+
+    while stack != 0 {
+        let top = b_sta + BLOCK_MOD - stack.leading_zeros() as usize;
+        if min_index::<T, O>(data, top, idx) == top {
+            break;
+        }
+
+        stack ^= 1 << (top & BLOCK_MOD);
+    }
+
+    stack | 1 << (idx & BLOCK_MOD)
+}
+
+/// Determines the minimum in the range [i, j] using the stacks of the blocks
+/// containing i and j. The given function determines the minimum in the
+/// blocks [l_b, r_b] between them.
+pub(super) fn block_query<T, O: Order<T>>(
+    data: &[T],
+    masks: &[u64],
+    i: usize,
+    j: usize,
+    blocks_min: impl FnOnce(usize, usize) -> usize,
+) -> usize {
+    // Determine block indices.
+    let i_b = i >> BLOCK_DIV;
+    let j_b = j >> BLOCK_DIV;
+
+    if i_b == j_b {
+        // i and j are in the same block.
+        return in_block_min(masks, i, j);
+    }
+
+    // i and j are in different blocks.
+
+    let i_min = in_block_min(masks, i, i | BLOCK_MOD);
+    let j_min = in_block_min(masks, j & !BLOCK_MOD, j);
+    let ij_min = min_index::<T, O>(data, i_min, j_min);
+
+    // Are blocks adjacent?
+    if i_b + 1 == j_b {
+        return ij_min;
+    }
+
+    // Determine the minimum in the blocks between i and j.
+    let b_min = blocks_min(i_b + 1, j_b - 1);
+
+    min_index::<T, O>(data, ij_min, b_min)
+}
+
+/// Determines the minimum in the range [i, j], which has to be inside a single
+/// block.
+#[inline]
+pub(super) fn in_block_min(masks: &[u64], i: usize, j: usize) -> usize {
+    // Only keep the entries of j's stack that are not before i.
+    let stack = masks[j] & (u64::MAX << (i & BLOCK_MOD));
+
+    (j & !BLOCK_MOD) + stack.trailing_zeros() as usize
+}
@@ -0,0 +1,79 @@
+use crate::tree::{NodeId, Tree, NULL_NODE};
+
+use super::{min_index, Natural, Order};
+
+/// Represents the Cartesian tree of some data together with the nearest
+/// smaller values of each entry.
+pub struct CartesianTree {
+    /// The Cartesian tree. The ID of a node is the index of its entry; the
+    /// root is the minimum of the whole data. If several entries have the same
+    /// value, the one preferred by the order's tie-breaking policy becomes the
+    /// ancestor of the others.
+    pub tree: Tree,
+
+    /// The index of the nearest entry to the left of each entry which is
+    /// preferred over it as minimum, or NULL_NODE if there is none. With the
+    /// default order, it is the previous strictly smaller entry.
+    pub prev_smaller: Vec<usize>,
+
+    /// The index of the nearest entry to the right of each entry which is
+    /// preferred over it as minimum, or NULL_NODE if there is none. With the
+    /// default order, it is the next smaller or equal entry.
+    pub next_smaller: Vec<usize>,
+}
+
+/// Builds the Cartesian tree (a min-heap) of the given data.
+/// The parent of each entry is the larger of its previous and next smaller
+/// entries. The tree and both arrays are computed in a single scan using the
+/// right-most path of the tree built so far as stack.
+/// Runtime: O(n)
+pub fn cartesian_tree<T: PartialOrd>(data: &[T]) -> CartesianTree {
+    cartesian_tree_by::<T, Natural>(data)
+}
+
+/// Builds the Cartesian tree of the given data with respect to the given
+/// order. For example, `Reverse` creates a max-heap.
+/// See `cartesian_tree()`.
+pub fn cartesian_tree_by<T, O: Order<T>>(data: &[T]) -> CartesianTree {
+    let n = data.len();
+
+    let mut parents = vec![NULL_NODE; n];
+    let mut prev_smaller = vec![NULL_NODE; n];
+    let mut next_smaller = vec![NULL_NODE; n];
+
+    // The right-most path of the tree built so far.
+    let mut stack: Vec<NodeId> = Vec::with_capacity(n);
+
+    for i in 0..n {
+        // Remove all nodes which are not preferred over i as minimum. The last
+        // removed node becomes the left child of i.
+        let mut last = NULL_NODE;
+        while let Some(&top) = stack.last() {
+            if min_index::<T, O>(data, top, i) == top {
+                break;
+            }
+
+            next_smaller[top] = i;
+            last = top;
+            stack.pop();
+        }
+
+        if last != NULL_NODE {
+            parents[last] = i;
+        }
+
+        // i becomes the right child of the remaining top.
+        if let Some(&top) = stack.last() {
+            parents[i] = top;
+            prev_smaller[i] = top;
+        }
+
+        stack.push(i);
+    }
+
+    CartesianTree {
+        tree: Tree::from_parents(parents),
+        prev_smaller,
+        next_smaller,
+    }
+}
@@ -0,0 +1,42 @@
+use super::{is_min, Natural, Order, Rmq};
+
+/// Performs a query on the given range of the given data, which is considered
+/// circular. If i > j, the range wraps around the end; that is, it is
+/// [i, n - 1] followed by [0, j].
+/// Returns the index of the minimum in that range. If there are several, the
+/// tie-breaking policy refers to their positions in the range. For example,
+/// the right-most one of a wrapped range is the last one in [0, j] (if any).
+/// Behaviour is undefined if the given range is invalid or the RMQ uses a
+/// different order.
+/// Runtime: two queries
+pub fn circular_query<T, R>(rmq: &R, data: &[T], i: usize, j: usize) -> usize
+where
+    T: PartialOrd,
+    R: Rmq<T>,
+{
+    circular_query_by::<T, Natural, R>(rmq, data, i, j)
+}
+
+/// Performs a query on the given circular range with respect to the given
+/// order.
+/// See `circular_query()`.
+pub fn circular_query_by<T, O, R>(rmq: &R, data: &[T], i: usize, j: usize) -> usize
+where
+    O: Order<T>,
+    R: Rmq<T>,
+{
+    if i <= j {
+        return rmq.query(i, j);
+    }
+
+    // Range wraps around.
+    let l_min = rmq.query(i, data.len() - 1);
+    let r_min = rmq.query(0, j);
+
+    // Entries of [i, n - 1] are before those of [0, j].
+    if is_min::<T, O>(&data[l_min], 0, &data[r_min], 1) {
+        l_min
+    } else {
+        r_min
+    }
+}
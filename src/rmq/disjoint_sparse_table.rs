@@ -0,0 +1,111 @@
+use std::{marker::PhantomData, rc::Rc};
+
+use crate::log::log_f;
+
+use super::{Rmq, Selection, Semigroup};
+
+/// Represents a sparse table that answers range queries for any associative
+/// operation.
+/// Unlike SparseTable, it does not combine overlapping ranges. Instead, level
+/// k splits the data into blocks of size 2^{k + 1}, and stores for each entry
+/// the result from it to the middle of its block. A range [i, j] is then
+/// covered by exactly two such entries, namely on the level of the highest bit
+/// in which i and j differ.
+/// Runtime: O(n log n) | O(1)
+pub struct DisjointSparseTable<T, Op: Semigroup<T>> {
+    data: Rc<[T]>,
+
+    /// Table with results for ranges ending or starting in middles of blocks.
+    table: Vec<Vec<Op::Output>>,
+
+    phantom_data: PhantomData<Op>,
+}
+
+impl<T, Op: Semigroup<T>> DisjointSparseTable<T, Op> {
+    /// Constructor.
+    pub fn new(data: Rc<[T]>) -> Self {
+        Self {
+            data,
+            table: Vec::new(),
+            phantom_data: PhantomData,
+        }
+    }
+
+    /// Pre-processes the data to allow queries.
+    pub fn process_data(&mut self) {
+        let data: &[T] = &self.data;
+        let n = data.len();
+
+        // Height of the table is floor(log n) + 1
+        let table_height = log_f(n) + 1;
+
+        self.table.clear();
+        self.table.reserve(table_height);
+
+        for k in 0..table_height {
+            let half = 1 << k;
+
+            let mut row = Vec::with_capacity(n);
+
+            let mut b_sta = 0;
+            while b_sta < n {
+                let mid = std::cmp::min(b_sta + half, n);
+                let b_end = std::cmp::min(b_sta + 2 * half, n);
+
+                // Left half: from each entry to the middle (exclusive).
+                // Computed backwards, then reversed.
+                let left_sta = row.len();
+                let mut acc = Op::lift(data, mid - 1);
+                row.push(acc.clone());
+                for i in (b_sta..(mid - 1)).rev() {
+                    acc = Op::combine(data, &Op::lift(data, i), &acc);
+                    row.push(acc.clone());
+                }
+                row[left_sta..].reverse();
+
+                // Right half: from the middle to each entry.
+                if mid < b_end {
+                    let mut acc = Op::lift(data, mid);
+                    row.push(acc.clone());
+                    for i in (mid + 1)..b_end {
+                        acc = Op::combine(data, &acc, &Op::lift(data, i));
+                        row.push(acc.clone());
+                    }
+                }
+
+                b_sta = b_end;
+            }
+
+            self.table.push(row);
+        }
+    }
+
+    /// Combines all entries in the given range [i, j].
+    /// Behaviour is undefined if the given range is invalid or pre-processing
+    /// has not been done.
+    pub fn fold(&self, i: usize, j: usize) -> Op::Output {
+        if i == j {
+            return Op::lift(&self.data, i);
+        }
+
+        // The highest bit in which i and j differ determines the level on
+        // which i and j are in different halves of the same block.
+        let k = log_f(i ^ j);
+
+        Op::combine(&self.data, &self.table[k][i], &self.table[k][j])
+    }
+}
+
+impl<T, Op: Selection<T>> Rmq<T> for DisjointSparseTable<T, Op> {
+    fn new(data: Rc<[T]>) -> Self {
+        Self::new(data)
+    }
+
+    fn process_data(&mut self) {
+        self.process_data();
+    }
+
+    fn query(&self, i: usize, j: usize) -> usize {
+        self.fold(i, j)
+    }
+}
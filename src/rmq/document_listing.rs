@@ -0,0 +1,69 @@
+use std::{collections::HashMap, hash::Hash, rc::Rc};
+
+use super::{all_min::report_minima, Rmq, SparseTable};
+
+/// Represents the document listing structure presented in [1]. It reports
+/// the distinct colours (documents) in a range of an array.
+/// For each entry, it stores the index of the previous entry with the same
+/// colour. An entry of a range [i, j] is the first of its colour in that range
+/// if that index is before i. Hence, the minimum of these indices in [i, j] is
+/// either such an entry or there are none. Splitting the range at the minimum
+/// finds all of them.
+/// Runtime: O(n) + RMQ | O(occ) queries
+///
+/// [1] S. Muthukrishnan:
+///     Efficient Algorithms for Document Retrieval Problems.
+///     SODA 2002, 657-666, 2002.
+pub struct DocumentListing<C, R: Rmq<usize> = SparseTable<usize>> {
+    colours: Rc<[C]>,
+
+    /// The index of the previous entry with the same colour plus 1, or 0 if
+    /// there is none. Shifted to avoid negative numbers.
+    prev: Rc<[usize]>,
+
+    /// A RMQ over prev[].
+    prev_rmq: R,
+}
+
+impl<C: Hash + Eq, R: Rmq<usize>> DocumentListing<C, R> {
+    /// Constructor.
+    pub fn new(colours: Rc<[C]>) -> Self {
+        Self {
+            colours,
+            prev: Rc::from(vec![].into_boxed_slice()),
+            prev_rmq: R::new(Rc::from(vec![].into_boxed_slice())),
+        }
+    }
+
+    /// Pre-processes the data to allow queries.
+    pub fn process_data(&mut self) {
+        let n = self.colours.len();
+
+        // The last entry of each colour seen so far.
+        let mut last: HashMap<&C, usize> = HashMap::new();
+
+        let mut prev = Vec::with_capacity(n);
+        for (idx, colour) in self.colours.iter().enumerate() {
+            prev.push(last.insert(colour, idx).map_or(0, |p_idx| p_idx + 1));
+        }
+
+        self.prev = Rc::from(prev.into_boxed_slice());
+        self.prev_rmq = R::new(self.prev.clone());
+        self.prev_rmq.process_data();
+    }
+
+    /// Determines the distinct colours in the range [i, j]. Each colour is
+    /// reported once by the index of its first entry in the range. The indices
+    /// are sorted in increasing order.
+    /// Behaviour is undefined if the given range is invalid or pre-processing
+    /// has not been done.
+    pub fn distinct_in_range(&self, i: usize, j: usize) -> Vec<usize> {
+        // Stop searching a range if all its colours occurred before i.
+        report_minima(&self.prev_rmq, i, j, |idx| self.prev[idx] <= i)
+    }
+
+    /// Returns the colour of the entry at the given index.
+    pub fn colour(&self, idx: usize) -> &C {
+        &self.colours[idx]
+    }
+}
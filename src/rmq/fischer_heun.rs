@@ -0,0 +1,418 @@
+use std::{marker::PhantomData, rc::Rc};
+
+use crate::log::{log_c, log_f};
+
+use super::{min_index, Natural, Order, Rmq};
+
+/// Represents the succinct RMQ algorithm presented in [1].
+/// The data is split into blocks of size s = 1/4 log n. Each block is
+/// classified by the shape of its Cartesian tree, which is encoded as a
+/// number in [0, C_s) using ballot numbers (C_s is the s-th Catalan number).
+/// Queries inside a block are answered by a lookup table per class. Queries
+/// over blocks use a sparse table inside each superblock (2^d >= log n blocks)
+/// and one over all superblocks, both bit-packed.
+/// In addition to the data, this takes per block log C_s <= 2s bits for the
+/// class and d^2 bits for the table inside its superblock (d levels of
+/// offsets with d bits each; level 0 is not stored). Per superblock, the
+/// table over superblocks takes about log^2 (n / (s 2^d)) bits. For n = 10^9
+/// (s = 7, d = 5), these are about 1.3 + 3.6 + 2.4 = 7.2 bits per entry.
+/// Runtime: O(n) | O(1)
+///
+/// [1] J. Fischer, V. Heun:
+///     Theoretical and Practical Improvements on the RMQ-Problem, with
+///     Applications to LCA and LCE.
+///     CPM 2006, LNCS 4009, 36-48, 2006.
+pub struct FischerHeun<T, O: Order<T> = Natural> {
+    data: Rc<[T]>,
+
+    /// States how large a single block of the data is.
+    block_size: usize,
+
+    /// States how many blocks form a superblock.
+    /// A power of two to allow shifts.
+    super_size: usize,
+    super_div: usize,
+
+    /// The number of blocks.
+    block_count: usize,
+
+    /// States for each block its class, i.e. its Cartesian tree number.
+    block_cls: PackedArray,
+
+    /// For each class and each pair (a, b) of indices in a block, the index of
+    /// the minimum in [a, b] relative to the block's start.
+    class_table: Vec<u8>,
+
+    /// The sparse tables over blocks inside each superblock. Entry (k, b)
+    /// stores the offset to b of the block with smallest minimum in
+    /// [b, b + 2^k - 1] (limited to the superblock of b). Level 0 is always 0
+    /// and not stored; see `block_offset()`.
+    block_table: PackedArray,
+
+    /// The sparse table over all superblocks. Entry (k, x) stores the
+    /// superblock with smallest minimum in [x, x + 2^k - 1].
+    super_table: PackedArray,
+
+    phantom_data: PhantomData<O>,
+}
+
+impl<T, O: Order<T>> Rmq<T> for FischerHeun<T, O> {
+    fn new(data: Rc<[T]>) -> Self {
+        Self {
+            data,
+            block_size: 0,
+            super_size: 0,
+            super_div: 0,
+            block_count: 0,
+            block_cls: PackedArray::new(0, 1),
+            class_table: Vec::new(),
+            block_table: PackedArray::new(0, 1),
+            super_table: PackedArray::new(0, 1),
+            phantom_data: PhantomData,
+        }
+    }
+
+    fn process_data(&mut self) {
+        let data: &[T] = &self.data;
+        let n = data.len();
+
+        // --- Determine sizes. ---
+
+        let log_n = log_f(n);
+
+        let s = std::cmp::max(1, log_n / 4);
+        self.block_size = s;
+
+        // Use the smallest power of 2 not smaller than log n.
+        self.super_div = log_c(std::cmp::max(2, log_n));
+        self.super_size = 1 << self.super_div;
+
+        // ceil(x / y) = floor((x - 1) / y) + 1
+        self.block_count = (n - 1) / s + 1;
+        let super_count = ((self.block_count - 1) >> self.super_div) + 1;
+
+        // --- Classify blocks. ---
+
+        let ballot = ballot_numbers(s);
+        let class_count = ballot[s][s];
+
+        self.block_cls = PackedArray::new(self.block_count, log_c(class_count));
+        self.class_table = vec![0; class_count * s * s];
+
+        let mut has_table = vec![false; class_count];
+
+        // The index of each block's minimum. Only needed during construction.
+        let mut block_min = Vec::with_capacity(self.block_count);
+
+        // Stack used to simulate the construction of Cartesian trees.
+        let mut stack = Vec::with_capacity(s);
+
+        for b in 0..self.block_count {
+            let b_sta = b * s;
+            let b_end = std::cmp::min(b_sta + s, n);
+
+            // Compute the Cartesian tree number of the block (Algorithm 1 in [1]).
+            // Entries that are not preferred over the current one as minimum
+            // are removed from the stack; equal entries thereby follow the
+            // tie-breaking policy. Missing entries of a shorter (i.e. the last)
+            // block act as +infinity and do not change the number.
+            let mut cls = 0;
+            let mut q = s;
+            stack.clear();
+            for i in b_sta..b_end {
+                while let Some(&top) = stack.last() {
+                    if min_index::<T, O>(data, top, i) == top {
+                        break;
+                    }
+
+                    cls += ballot[s - (i - b_sta) - 1][q];
+                    q -= 1;
+                    stack.pop();
+                }
+
+                stack.push(i);
+            }
+
+            self.block_cls.set(b, cls);
+
+            // Has that class a table?
+            if !has_table[cls] {
+                has_table[cls] = true;
+
+                let table = &mut self.class_table[(cls * s * s)..((cls + 1) * s * s)];
+                for i in b_sta..b_end {
+                    let mut min_idx = i;
+                    for j in i..b_end {
+                        min_idx = min_index::<T, O>(data, min_idx, j);
+                        table[(i - b_sta) * s + (j - b_sta)] = (min_idx - b_sta) as u8;
+                    }
+
+                    // Ranges reaching into missing entries use the last
+                    // existing one.
+                    for j in b_end..(b_sta + s) {
+                        table[(i - b_sta) * s + (j - b_sta)] = (min_idx - b_sta) as u8;
+                    }
+                }
+            }
+
+            block_min.push(self.in_block_min(b, 0, s - 1));
+        }
+
+        // --- Build sparse tables over blocks inside superblocks. ---
+
+        // Offsets are smaller than the size of a superblock, 2^super_div.
+        let block_height = self.super_div + 1;
+        self.block_table = PackedArray::new((block_height - 1) * self.block_count, self.super_div);
+
+        for k in 1..block_height {
+            let curr = (k - 1) * self.block_count;
+
+            for b in 0..self.block_count {
+                // Check that right index is not out of the superblock.
+                let sb_end = std::cmp::min(
+                    ((b >> self.super_div) + 1) << self.super_div,
+                    self.block_count,
+                );
+                let r = std::cmp::min(sb_end - 1, b + (1 << (k - 1)));
+
+                let l_min = b + self.block_offset(k - 1, b);
+                let r_min = r + self.block_offset(k - 1, r);
+
+                let min_b = if min_index::<T, O>(data, block_min[l_min], block_min[r_min])
+                    == block_min[l_min]
+                {
+                    l_min
+                } else {
+                    r_min
+                };
+
+                self.block_table.set(curr + b, min_b - b);
+            }
+        }
+
+        // --- Build sparse table over superblocks. ---
+
+        let super_height = log_f(super_count) + 1;
+        self.super_table = PackedArray::new(super_height * super_count, log_c(super_count));
+
+        for x in 0..super_count {
+            self.super_table.set(x, x);
+        }
+
+        for k in 1..super_height {
+            let prev = (k - 1) * super_count;
+            let curr = k * super_count;
+
+            for x in 0..super_count {
+                let r = std::cmp::min(super_count - 1, x + (1 << (k - 1)));
+
+                let l_min = self.super_table.get(prev + x);
+                let r_min = self.super_table.get(prev + r);
+
+                let l_idx = self.super_min(l_min);
+                let r_idx = self.super_min(r_min);
+
+                let min_x = if min_index::<T, O>(data, l_idx, r_idx) == l_idx {
+                    l_min
+                } else {
+                    r_min
+                };
+
+                self.super_table.set(curr + x, min_x);
+            }
+        }
+    }
+
+    fn query(&self, i: usize, j: usize) -> usize {
+        let s = self.block_size;
+
+        // Determine block indices.
+        let i_b = i / s;
+        let j_b = j / s;
+
+        if i_b == j_b {
+            // i and j are in the same block.
+            return self.in_block_min(i_b, i - i_b * s, j - j_b * s);
+        }
+
+        // i and j are in different blocks.
+
+        let i_min = self.in_block_min(i_b, i - i_b * s, s - 1);
+        let j_min = self.in_block_min(j_b, 0, j - j_b * s);
+        let mut min_idx = min_index::<T, O>(&self.data, i_min, j_min);
+
+        // Are blocks adjacent?
+        if i_b + 1 == j_b {
+            return min_idx;
+        }
+
+        // Determine the minimum in the blocks between i and j.
+        let x = i_b + 1;
+        let y = j_b - 1;
+
+        let x_s = x >> self.super_div;
+        let y_s = y >> self.super_div;
+
+        if x_s == y_s {
+            let b_min = self.block_range_min(x, y);
+            return min_index::<T, O>(&self.data, min_idx, b_min);
+        }
+
+        // Blocks in the superblocks of x and y.
+        let x_min = self.block_range_min(x, ((x_s + 1) << self.super_div) - 1);
+        let y_min = self.block_range_min(y_s << self.super_div, y);
+        min_idx = min_index::<T, O>(&self.data, min_idx, x_min);
+        min_idx = min_index::<T, O>(&self.data, min_idx, y_min);
+
+        // Are superblocks adjacent?
+        if x_s + 1 == y_s {
+            return min_idx;
+        }
+
+        // Superblocks between x and y.
+        let super_count = ((self.block_count - 1) >> self.super_div) + 1;
+
+        let k = log_f(y_s - x_s - 1);
+        let min_1 = self.super_table.get(k * super_count + x_s + 1);
+        let min_2 = self.super_table.get(k * super_count + y_s - (1 << k));
+
+        min_idx = min_index::<T, O>(&self.data, min_idx, self.super_min(min_1));
+        min_index::<T, O>(&self.data, min_idx, self.super_min(min_2))
+    }
+}
+
+impl<T, O: Order<T>> FischerHeun<T, O> {
+    /// Performs a query on the given block and given range.
+    /// Returns the index of the minimum entry in that range with respect to the
+    /// original data.
+    #[inline]
+    fn in_block_min(&self, b: usize, i: usize, j: usize) -> usize {
+        let s = self.block_size;
+        let cls = self.block_cls.get(b);
+
+        b * s + self.class_table[cls * s * s + i * s + j] as usize
+    }
+
+    /// Determines the index of the minimum in the blocks [x, y], which are in
+    /// the same superblock.
+    #[inline]
+    fn block_range_min(&self, x: usize, y: usize) -> usize {
+        let s = self.block_size;
+        let k = log_f(y - x + 1);
+
+        let b_1 = x + self.block_offset(k, x);
+        let y_k = y + 1 - (1 << k);
+        let b_2 = y_k + self.block_offset(k, y_k);
+
+        let min_1 = self.in_block_min(b_1, 0, s - 1);
+        let min_2 = self.in_block_min(b_2, 0, s - 1);
+
+        min_index::<T, O>(&self.data, min_1, min_2)
+    }
+
+    /// Returns entry (k, b) of the sparse tables over blocks.
+    #[inline]
+    fn block_offset(&self, k: usize, b: usize) -> usize {
+        if k == 0 {
+            0
+        } else {
+            self.block_table.get((k - 1) * self.block_count + b)
+        }
+    }
+
+    /// Determines the index of the minimum in the given superblock.
+    #[inline]
+    fn super_min(&self, x: usize) -> usize {
+        let s = self.block_size;
+        let b = x << self.super_div;
+
+        // The top level of the block table covers the whole superblock.
+        let b_min = b + self.block_offset(self.super_div, b);
+
+        self.in_block_min(b_min, 0, s - 1)
+    }
+}
+
+/// Computes the ballot numbers C_{pq} for 0 <= p, q <= s.
+/// C_{00} = 1, C_{pq} = C_{p(q - 1)} + C_{(p - 1)q} if 0 <= p <= q != 0, and
+/// C_{pq} = 0 otherwise.
+fn ballot_numbers(s: usize) -> Vec<Vec<usize>> {
+    let mut ballot = vec![vec![0; s + 1]; s + 1];
+
+    ballot[0][0] = 1;
+    for q in 1..=s {
+        for p in 0..=q {
+            let left = ballot[p][q - 1];
+            let down = if p > 0 { ballot[p - 1][q] } else { 0 };
+            ballot[p][q] = left + down;
+        }
+    }
+
+    ballot
+}
+
+/// Represents an array of unsigned integers that all use the same number of
+/// bits.
+struct PackedArray {
+    /// The number of bits per entry.
+    width: usize,
+
+    /// The bits of all entries.
+    words: Vec<u64>,
+}
+
+impl PackedArray {
+    /// Creates an array with the given number of entries which are all 0.
+    fn new(len: usize, width: usize) -> Self {
+        Self {
+            width,
+            words: vec![0; (len * width).div_ceil(64)],
+        }
+    }
+
+    /// Returns the mask of a single entry.
+    #[inline(always)]
+    fn mask(&self) -> u64 {
+        if self.width == 64 {
+            u64::MAX
+        } else {
+            (1 << self.width) - 1
+        }
+    }
+
+    /// Returns the entry at the given index.
+    #[inline(always)]
+    fn get(&self, idx: usize) -> usize {
+        let bit = idx * self.width;
+        let w = bit >> 6;
+        let o = bit & 63;
+
+        let mut val = self.words[w] >> o;
+
+        // Does the entry continue in the next word?
+        if o + self.width > 64 {
+            val |= self.words[w + 1] << (64 - o);
+        }
+
+        (val & self.mask()) as usize
+    }
+
+    /// Sets the entry at the given index.
+    fn set(&mut self, idx: usize, val: usize) {
+        let bit = idx * self.width;
+        let w = bit >> 6;
+        let o = bit & 63;
+
+        let val = val as u64 & self.mask();
+
+        self.words[w] &= !(self.mask() << o);
+        self.words[w] |= val << o;
+
+        // Does the entry continue in the next word?
+        if o + self.width > 64 {
+            let rest = o + self.width - 64;
+            self.words[w + 1] &= !((1 << rest) - 1);
+            self.words[w + 1] |= val >> (64 - o);
+        }
+    }
+}
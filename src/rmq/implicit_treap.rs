@@ -0,0 +1,316 @@
+use std::{marker::PhantomData, rc::Rc};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::{is_min, Natural, Order, Rmq};
+
+/// A (possibly empty) subtree of an ImplicitTreap.
+type Link<T> = Option<Box<Node<T>>>;
+
+/// Represents a node of an ImplicitTreap.
+struct Node<T> {
+    value: T,
+
+    /// The heap key. A node's priority is larger than those of its children.
+    priority: u64,
+
+    /// The number of nodes in the subtree.
+    size: usize,
+
+    /// The minimum of the subtree.
+    min_val: T,
+
+    /// The position of that minimum inside the subtree.
+    min_pos: usize,
+
+    left: Link<T>,
+    right: Link<T>,
+}
+
+/// Represents a RMQ over a sequence that allows to insert and remove entries
+/// at any position, and to split and concatenate sequences.
+/// The entries are stored in a treap whose keys are implicit: the position of
+/// an entry is the number of nodes before it in the in-order. Each node stores
+/// the minimum of its subtree.
+/// Runtime: O(n log n) | O(log n), updates O(log n); all expected
+pub struct ImplicitTreap<T, O: Order<T> = Natural> {
+    /// The data given to the constructor. Dropped once the treap is built
+    /// from it.
+    data: Option<Rc<[T]>>,
+    root: Link<T>,
+
+    /// Generates the priorities of new nodes.
+    rng: StdRng,
+
+    phantom_data: PhantomData<O>,
+}
+
+impl<T: Clone, O: Order<T>> ImplicitTreap<T, O> {
+    /// Constructor. The priorities of nodes are random.
+    pub fn new(data: Rc<[T]>) -> Self {
+        Self::with_rng(data, StdRng::from_entropy())
+    }
+
+    /// Constructor. The priorities of nodes are generated from the given seed,
+    /// which makes the shape of the treap reproducible.
+    pub fn with_seed(data: Rc<[T]>, seed: u64) -> Self {
+        Self::with_rng(data, StdRng::seed_from_u64(seed))
+    }
+
+    /// Constructor with the given generator for priorities.
+    fn with_rng(data: Rc<[T]>, rng: StdRng) -> Self {
+        Self {
+            data: Some(data),
+            root: None,
+            rng,
+            phantom_data: PhantomData,
+        }
+    }
+
+    /// Pre-processes the data to allow queries.
+    /// Builds the sequence from the data given to the constructor, which is
+    /// not kept afterwards. Hence, later calls (also on sequences created by
+    /// split() or concat()) do not change the sequence.
+    pub fn process_data(&mut self) {
+        let Some(data) = self.data.take() else {
+            return;
+        };
+
+        for value in data.iter() {
+            let node = self.new_node(value.clone());
+            self.root = Self::merge(self.root.take(), node);
+        }
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        Self::size(&self.root)
+    }
+
+    /// Determines if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Returns the entry at the given position.
+    /// Behaviour is undefined if the position is invalid or pre-processing has
+    /// not been done.
+    pub fn get(&self, pos: usize) -> &T {
+        let mut pos = pos;
+        let mut node = self.root.as_ref().unwrap();
+
+        loop {
+            let l_size = Self::size(&node.left);
+
+            if pos < l_size {
+                node = node.left.as_ref().unwrap();
+            } else if pos == l_size {
+                return &node.value;
+            } else {
+                pos -= l_size + 1;
+                node = node.right.as_ref().unwrap();
+            }
+        }
+    }
+
+    /// Performs a query on the given range of positions.
+    /// Returns the position of the minimum in that range.
+    /// Behaviour is undefined if the given range is invalid or pre-processing
+    /// has not been done.
+    pub fn query(&self, i: usize, j: usize) -> usize {
+        Self::query_node(self.root.as_ref().unwrap(), i, j).1
+    }
+
+    /// Inserts the given value such that it is at the given position. All
+    /// entries from that position on move one position to the right.
+    /// Behaviour is undefined if the position is larger than the length or
+    /// pre-processing has not been done.
+    pub fn insert(&mut self, pos: usize, value: T) {
+        let node = self.new_node(value);
+
+        let (left, right) = Self::split_node(self.root.take(), pos);
+        self.root = Self::merge(Self::merge(left, node), right);
+    }
+
+    /// Removes the entry at the given position and returns it. All entries
+    /// after it move one position to the left.
+    /// Behaviour is undefined if the position is invalid or pre-processing has
+    /// not been done.
+    pub fn remove(&mut self, pos: usize) -> T {
+        let (left, right) = Self::split_node(self.root.take(), pos);
+        let (node, right) = Self::split_node(right, 1);
+
+        self.root = Self::merge(left, right);
+        node.unwrap().value
+    }
+
+    /// Splits the sequence into the entries before the given position and
+    /// those starting with it.
+    /// Behaviour is undefined if the position is larger than the length or
+    /// pre-processing has not been done.
+    pub fn split(mut self, pos: usize) -> (Self, Self) {
+        let (left, right) = Self::split_node(self.root.take(), pos);
+        let seed = self.rng.gen();
+
+        let right = Self {
+            data: None,
+            root: right,
+            rng: StdRng::seed_from_u64(seed),
+            phantom_data: PhantomData,
+        };
+
+        self.root = left;
+        (self, right)
+    }
+
+    /// Appends the entries of the given sequence to this one.
+    /// Behaviour is undefined if pre-processing has not been done.
+    pub fn concat(mut self, other: Self) -> Self {
+        self.root = Self::merge(self.root.take(), other.root);
+        self
+    }
+
+    /// Creates a single node with the given value and a random priority.
+    fn new_node(&mut self, value: T) -> Link<T> {
+        Some(Box::new(Node {
+            min_val: value.clone(),
+            value,
+            priority: self.rng.gen(),
+            size: 1,
+            min_pos: 0,
+            left: None,
+            right: None,
+        }))
+    }
+
+    /// Combines the given subtrees such that all nodes of a are before those
+    /// of b.
+    fn merge(a: Link<T>, b: Link<T>) -> Link<T> {
+        match (a, b) {
+            (None, b) => b,
+            (a, None) => a,
+            (Some(mut a), Some(mut b)) => {
+                if a.priority > b.priority {
+                    a.right = Self::merge(a.right.take(), Some(b));
+                    Self::repair(&mut a);
+                    Some(a)
+                } else {
+                    b.left = Self::merge(Some(a), b.left.take());
+                    Self::repair(&mut b);
+                    Some(b)
+                }
+            }
+        }
+    }
+
+    /// Splits the given subtree into the first k nodes and the remaining ones.
+    fn split_node(link: Link<T>, k: usize) -> (Link<T>, Link<T>) {
+        let Some(mut node) = link else {
+            return (None, None);
+        };
+
+        let l_size = Self::size(&node.left);
+
+        if k <= l_size {
+            let (left, right) = Self::split_node(node.left.take(), k);
+            node.left = right;
+            Self::repair(&mut node);
+            (left, Some(node))
+        } else {
+            let (left, right) = Self::split_node(node.right.take(), k - l_size - 1);
+            node.right = left;
+            Self::repair(&mut node);
+            (Some(node), right)
+        }
+    }
+
+    /// Determines the minimum (value and position) of the entries of the given
+    /// subtree that are in the range [i, j] of positions inside the subtree.
+    fn query_node(node: &Node<T>, i: usize, j: usize) -> (&T, usize) {
+        if i == 0 && j + 1 == node.size {
+            // Subtree is covered completely.
+            return (&node.min_val, node.min_pos);
+        }
+
+        let l_size = Self::size(&node.left);
+
+        if j < l_size {
+            // Range is in left subtree.
+            return Self::query_node(node.left.as_ref().unwrap(), i, j);
+        }
+
+        if i > l_size {
+            // Range is in right subtree.
+            let r_node = node.right.as_ref().unwrap();
+            let (val, pos) = Self::query_node(r_node, i - l_size - 1, j - l_size - 1);
+            return (val, pos + l_size + 1);
+        }
+
+        // Range contains the node itself.
+        let mut min = (&node.value, l_size);
+
+        if i < l_size {
+            let l_min = Self::query_node(node.left.as_ref().unwrap(), i, l_size - 1);
+            min = Self::min_of(l_min, min);
+        }
+
+        if j > l_size {
+            let r_node = node.right.as_ref().unwrap();
+            let (val, pos) = Self::query_node(r_node, 0, j - l_size - 1);
+            min = Self::min_of(min, (val, pos + l_size + 1));
+        }
+
+        min
+    }
+
+    /// Restores the size and minimum of the given node from its children.
+    fn repair(node: &mut Node<T>) {
+        let l_size = Self::size(&node.left);
+        let mut min = (&node.value, l_size);
+
+        if let Some(left) = &node.left {
+            min = Self::min_of((&left.min_val, left.min_pos), min);
+        }
+
+        if let Some(right) = &node.right {
+            min = Self::min_of(min, (&right.min_val, right.min_pos + l_size + 1));
+        }
+
+        let (min_val, min_pos) = (min.0.clone(), min.1);
+
+        node.min_val = min_val;
+        node.min_pos = min_pos;
+        node.size = l_size + 1 + Self::size(&node.right);
+    }
+
+    /// Determines which of the given minima (value and position) is the
+    /// minimum of both.
+    #[inline]
+    fn min_of<'a>(a: (&'a T, usize), b: (&'a T, usize)) -> (&'a T, usize) {
+        if is_min::<T, O>(a.0, a.1, b.0, b.1) {
+            a
+        } else {
+            b
+        }
+    }
+
+    /// Returns the number of nodes in the given subtree.
+    #[inline]
+    fn size(link: &Link<T>) -> usize {
+        link.as_ref().map_or(0, |node| node.size)
+    }
+}
+
+impl<T: Clone, O: Order<T>> Rmq<T> for ImplicitTreap<T, O> {
+    fn new(data: Rc<[T]>) -> Self {
+        Self::new(data)
+    }
+
+    fn process_data(&mut self) {
+        self.process_data();
+    }
+
+    fn query(&self, i: usize, j: usize) -> usize {
+        self.query(i, j)
+    }
+}
@@ -0,0 +1,277 @@
+use std::{marker::PhantomData, ops::Add, rc::Rc};
+
+use super::{
+    is_min,
+    segment_tree::{build_tree, Node},
+    Natural, Order, Rmq, RmqUpdate, TieBreak,
+};
+
+/// Represents an update of all entries in a range.
+#[derive(Clone)]
+enum Tag<T> {
+    /// Adds the value to each entry.
+    Add(T),
+
+    /// Replaces each entry with the value.
+    Assign(T),
+}
+
+impl<T: Clone + Add<Output = T>> Tag<T> {
+    /// Returns the tag that has the same effect as first applying the given
+    /// (optional) tag and then this one.
+    fn after(&self, first: Option<&Tag<T>>) -> Tag<T> {
+        match (first, self) {
+            (_, Tag::Assign(x)) => Tag::Assign(x.clone()),
+            (Some(Tag::Assign(x)), Tag::Add(c)) => Tag::Assign(x.clone() + c.clone()),
+            (Some(Tag::Add(d)), Tag::Add(c)) => Tag::Add(d.clone() + c.clone()),
+            (None, Tag::Add(c)) => Tag::Add(c.clone()),
+        }
+    }
+
+    /// Applies the tag to the minimum (value and index) of the range [fr, to].
+    fn apply<O: Order<T>>(&self, min: (T, usize), fr: usize, to: usize) -> (T, usize) {
+        match self {
+            // Adding a constant does not change the position of the minimum.
+            Tag::Add(c) => (min.0 + c.clone(), min.1),
+
+            // All entries are equal now.
+            Tag::Assign(x) => match O::TIE_BREAK {
+                TieBreak::Leftmost => (x.clone(), fr),
+                TieBreak::Rightmost => (x.clone(), to),
+            },
+        }
+    }
+}
+
+/// The value of a node of a LazySegmentTree.
+#[derive(Clone)]
+struct Entry<T> {
+    /// The minimum of the node's covered range.
+    min_val: T,
+
+    /// The index of that minimum.
+    min_idx: usize,
+
+    /// The update that has been applied to the node but not yet to its
+    /// children.
+    tag: Option<Tag<T>>,
+}
+
+/// Represents a RMQ that uses a segment tree with lazy propagation.
+/// Allows to add a value to all entries in a range or to replace all of them
+/// with a value in O(log n).
+/// Runtime: O(n) | O(log n)
+pub struct LazySegmentTree<T, O: Order<T> = Natural> {
+    /// The original data. Not changed by updates.
+    data: Rc<[T]>,
+    tree: Vec<Node<Entry<T>>>,
+    phantom_data: PhantomData<O>,
+}
+
+impl<T: Clone + Add<Output = T>, O: Order<T>> LazySegmentTree<T, O> {
+    /// Constructor.
+    pub fn new(data: Rc<[T]>) -> Self {
+        Self {
+            data,
+            tree: Vec::new(),
+            phantom_data: PhantomData,
+        }
+    }
+
+    /// Pre-processes the data to allow queries.
+    pub fn process_data(&mut self) {
+        let data: &[T] = &self.data;
+
+        self.tree = build_tree(
+            data.len(),
+            |i| Entry {
+                min_val: data[i].clone(),
+                min_idx: i,
+                tag: None,
+            },
+            |a, b| Self::combine(a, b),
+        );
+    }
+
+    /// Performs a query on the given range.
+    /// Returns the index of the minimum in that range.
+    /// Behaviour is undefined if the given range is invalid or pre-processing
+    /// has not been done.
+    pub fn query(&self, i: usize, j: usize) -> usize {
+        self.query_node(0, i, j, None).unwrap().1
+    }
+
+    /// Returns the current value of the entry at the given index.
+    /// Behaviour is undefined if pre-processing has not been done.
+    pub fn get(&self, idx: usize) -> T {
+        self.query_node(0, idx, idx, None).unwrap().0
+    }
+
+    /// Adds the given value to all entries in the range [i, j].
+    /// Behaviour is undefined if the given range is invalid or pre-processing
+    /// has not been done.
+    pub fn add(&mut self, i: usize, j: usize, value: T) {
+        self.update_node(0, i, j, &Tag::Add(value));
+    }
+
+    /// Replaces all entries in the range [i, j] with the given value.
+    /// Behaviour is undefined if the given range is invalid or pre-processing
+    /// has not been done.
+    pub fn assign(&mut self, i: usize, j: usize, value: T) {
+        self.update_node(0, i, j, &Tag::Assign(value));
+    }
+
+    /// Applies the given tag to the entries of the given node that are in the
+    /// range [i, j].
+    fn update_node(&mut self, node_idx: usize, i: usize, j: usize, tag: &Tag<T>) {
+        let node = &self.tree[node_idx];
+
+        if j < node.fr_idx || node.to_idx < i {
+            // Disjoint ranges.
+            return;
+        }
+
+        if i <= node.fr_idx && node.to_idx <= j {
+            // Node is covered completely.
+            self.apply(node_idx, tag);
+            return;
+        }
+
+        // Node is covered partially. Pass pending update on to the children
+        // first, since they are changed now.
+        let (left, right) = (node.left, node.right);
+        self.push_down(node_idx);
+
+        self.update_node(left, i, j, tag);
+        if right != usize::MAX {
+            self.update_node(right, i, j, tag);
+        }
+
+        // Repair node.
+        let l_entry = &self.tree[left].value;
+        let value = if right == usize::MAX {
+            Self::combine(l_entry, l_entry)
+        } else {
+            Self::combine(l_entry, &self.tree[right].value)
+        };
+        self.tree[node_idx].value = value;
+    }
+
+    /// Applies the given tag to the given node and remembers it for the node's
+    /// children.
+    fn apply(&mut self, node_idx: usize, tag: &Tag<T>) {
+        let node = &mut self.tree[node_idx];
+        let entry = &mut node.value;
+
+        let min = (entry.min_val.clone(), entry.min_idx);
+        (entry.min_val, entry.min_idx) = tag.apply::<O>(min, node.fr_idx, node.to_idx);
+
+        // Leafs have no children.
+        if node.left != usize::MAX {
+            entry.tag = Some(tag.after(entry.tag.as_ref()));
+        }
+    }
+
+    /// Applies the pending update of the given node to its children.
+    fn push_down(&mut self, node_idx: usize) {
+        let node = &mut self.tree[node_idx];
+
+        if let Some(tag) = node.value.tag.take() {
+            let (left, right) = (node.left, node.right);
+
+            self.apply(left, &tag);
+            if right != usize::MAX {
+                self.apply(right, &tag);
+            }
+        }
+    }
+
+    /// Determines the minimum (value and index) of the entries of the given
+    /// node that are in the range [i, j]. Returns None if there are none.
+    /// The given tag is the combined pending update of the node's ancestors.
+    fn query_node(
+        &self,
+        node_idx: usize,
+        i: usize,
+        j: usize,
+        pending: Option<Tag<T>>,
+    ) -> Option<(T, usize)> {
+        let node = &self.tree[node_idx];
+        let entry = &node.value;
+
+        if j < node.fr_idx || node.to_idx < i {
+            // Disjoint ranges.
+            return None;
+        }
+
+        if i <= node.fr_idx && node.to_idx <= j {
+            // Node is covered completely.
+            let min = (entry.min_val.clone(), entry.min_idx);
+            return Some(match pending {
+                Some(tag) => tag.apply::<O>(min, node.fr_idx, node.to_idx),
+                None => min,
+            });
+        }
+
+        // The node's own pending update is older than those of its ancestors.
+        let pending = match pending {
+            Some(tag) => Some(tag.after(entry.tag.as_ref())),
+            None => entry.tag.clone(),
+        };
+
+        let l_min = self.query_node(node.left, i, j, pending.clone());
+        if node.right == usize::MAX {
+            return l_min;
+        }
+        let r_min = self.query_node(node.right, i, j, pending);
+
+        match (l_min, r_min) {
+            (Some(l), Some(r)) => {
+                if is_min::<T, O>(&l.0, l.1, &r.0, r.1) {
+                    Some(l)
+                } else {
+                    Some(r)
+                }
+            }
+            (l, None) => l,
+            (None, r) => r,
+        }
+    }
+
+    /// Determines the value of a node from the values of its children. A node
+    /// with a single child combines that child with itself.
+    #[inline]
+    fn combine(a: &Entry<T>, b: &Entry<T>) -> Entry<T> {
+        let min = if is_min::<T, O>(&a.min_val, a.min_idx, &b.min_val, b.min_idx) {
+            a
+        } else {
+            b
+        };
+
+        Entry {
+            min_val: min.min_val.clone(),
+            min_idx: min.min_idx,
+            tag: None,
+        }
+    }
+}
+
+impl<T: Clone + Add<Output = T>, O: Order<T>> Rmq<T> for LazySegmentTree<T, O> {
+    fn new(data: Rc<[T]>) -> Self {
+        Self::new(data)
+    }
+
+    fn process_data(&mut self) {
+        self.process_data();
+    }
+
+    fn query(&self, i: usize, j: usize) -> usize {
+        self.query(i, j)
+    }
+}
+
+impl<T: Clone + Add<Output = T>, O: Order<T>> RmqUpdate<T> for LazySegmentTree<T, O> {
+    fn update(&mut self, idx: usize, value: T) {
+        self.assign(idx, idx, value);
+    }
+}
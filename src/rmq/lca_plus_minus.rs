@@ -0,0 +1,58 @@
+use std::{marker::PhantomData, rc::Rc};
+
+use crate::tree::EulerTour;
+
+use super::{cartesian_tree_by, Natural, Order, PlusMinus, Rmq};
+
+/// Represents an RMQ algorithm for arbitrary sequences that reduces the
+/// problem to LCA and the LCA problem to +-1 RMQ.
+/// The minimum in a range [i, j] is the lowest common ancestor of i and j in
+/// the Cartesian tree of the data. That ancestor is found with a +-1 RMQ over
+/// the levels of an Euler tour of the tree.
+/// Runtime: O(n) | O(1)
+pub struct LcaPlusMinus<T, O: Order<T> = Natural> {
+    data: Rc<[T]>,
+
+    /// An Euler tour of the Cartesian tree of the data.
+    et: EulerTour,
+
+    /// A RMQ over the levels in the Euler tour.
+    level_rmq: PlusMinus<usize>,
+
+    phantom_data: PhantomData<O>,
+}
+
+impl<T, O: Order<T>> Rmq<T> for LcaPlusMinus<T, O> {
+    fn new(data: Rc<[T]>) -> Self {
+        Self {
+            data,
+            et: EulerTour {
+                e: Rc::from(vec![].into_boxed_slice()),
+                l: Rc::from(vec![].into_boxed_slice()),
+                r: Rc::from(vec![].into_boxed_slice()),
+            },
+            level_rmq: PlusMinus::new(Rc::from(vec![].into_boxed_slice())),
+            phantom_data: PhantomData,
+        }
+    }
+
+    fn process_data(&mut self) {
+        let tree = cartesian_tree_by::<T, O>(&self.data).tree;
+
+        self.et = tree.euler_tour();
+        self.level_rmq = PlusMinus::new(self.et.l.clone());
+        self.level_rmq.process_data();
+    }
+
+    fn query(&self, i: usize, j: usize) -> usize {
+        // Node IDs in the Cartesian tree are the indices in the data.
+        let r_i = self.et.r[i];
+        let r_j = self.et.r[j];
+
+        // Ensure that r_i <= r_j.
+        let (r_i, r_j) = if r_i <= r_j { (r_i, r_j) } else { (r_j, r_i) };
+
+        // RMQ(i, j) = LCA(i, j) = E[rmq(R[i], R[j])]
+        self.et.e[self.level_rmq.query(r_i, r_j)]
+    }
+}
@@ -1,16 +1,64 @@
 use std::{marker::PhantomData, rc::Rc};
 
+mod all_min;
+mod append_only;
+mod block_bitmask;
+mod cartesian_tree;
+mod circular;
+mod disjoint_sparse_table;
+mod document_listing;
+mod fischer_heun;
+mod implicit_treap;
+mod lazy_segment_tree;
+mod lca_plus_minus;
 mod naive;
 mod no_preprocessing;
+mod offline;
+mod order;
+mod persistent_segment_tree;
 mod plus_minus;
 mod segment_tree;
+mod segment_tree_2d;
+mod segment_tree_beats;
+mod segment_tree_cache;
+mod semigroup;
+mod sliding_window;
 mod sparse_table;
+mod sparse_table_2d;
+mod sqrt_decomposition;
+mod threshold;
+mod top_k;
 
+pub use all_min::{all_min_positions, all_min_positions_by};
+pub use append_only::AppendOnly;
+pub use block_bitmask::BlockBitmask;
+pub use cartesian_tree::{cartesian_tree, cartesian_tree_by, CartesianTree};
+pub use circular::{circular_query, circular_query_by};
+pub use disjoint_sparse_table::DisjointSparseTable;
+pub use document_listing::DocumentListing;
+pub use fischer_heun::FischerHeun;
+pub use implicit_treap::ImplicitTreap;
+pub use lazy_segment_tree::LazySegmentTree;
+pub use lca_plus_minus::LcaPlusMinus;
 pub use naive::Naive;
 pub use no_preprocessing::NoPreprocessing;
+pub use offline::{offline_rmq, offline_rmq_by};
+pub use order::{ByKey, Key, Leftmost, Natural, Order, Reverse, Rightmost, Then, TieBreak};
+pub use persistent_segment_tree::PersistentSegmentTree;
 pub use plus_minus::PlusMinus;
 pub use segment_tree::SegmentTree;
+pub use segment_tree_2d::SegmentTree2D;
+pub use segment_tree_beats::SegmentTreeBeats;
+pub use segment_tree_cache::SegmentTreeCache;
+pub use semigroup::{
+    BitAnd, BitOr, Gcd, Idempotent, Lcm, Max, Min, MinTwo, Product, Selection, Semigroup, Sum,
+};
+pub use sliding_window::{window_minima, window_minima_by, SlidingWindow, WindowMinima};
 pub use sparse_table::SparseTable;
+pub use sparse_table_2d::SparseTable2D;
+pub use sqrt_decomposition::SqrtDecomposition;
+pub use threshold::{find_first_below, find_first_below_by, find_last_below, find_last_below_by};
+pub use top_k::{top_k, top_k_by};
 
 /// Represents an RMQ algorithms.
 pub trait Rmq<T> {
@@ -21,23 +69,47 @@ pub trait Rmq<T> {
     fn process_data(&mut self);
 
     /// Performs a query on the given data and given range.
-    /// Returns the index of the minimum in that range.
+    /// Returns the index of the minimum in that range. If there are several,
+    /// the tie-breaking policy of the algorithm's order determines which one
+    /// (the right-most one by default).
     /// Behaviour is undefined if the given range is invalid or pre-processing
     /// has not been done.
     fn query(&self, i: usize, j: usize) -> usize;
 }
 
-/// Determines which of these indices stores the smaller value.
+/// Represents an RMQ algorithm that allows to change entries of the data
+/// after pre-processing.
+pub trait RmqUpdate<T>: Rmq<T> {
+    /// Replaces the entry at the given index with the given value.
+    /// Behaviour is undefined if pre-processing has not been done.
+    fn update(&mut self, idx: usize, value: T);
+}
+
+/// Determines which of these indices stores the smaller value with respect to
+/// the given order. If both values are equal, the order's tie-breaking policy
+/// determines the index.
 #[inline]
-fn min_index<T: PartialOrd>(data: &[T], i: usize, j: usize) -> usize {
+fn min_index<T, O: Order<T>>(data: &[T], i: usize, j: usize) -> usize {
     // ToDo: Make unchecked.
-    if data[i] < data[j] {
+    if is_min::<T, O>(&data[i], i, &data[j], j) {
         i
     } else {
         j
     }
 }
 
+/// Determines if the value a at index i is the minimum of it and the value b
+/// at index j with respect to the given order. If both values are equal, the
+/// order's tie-breaking policy decides.
+/// Used by algorithms that do not keep the values in a slice.
+#[inline]
+fn is_min<T, O: Order<T>>(a: &T, i: usize, b: &T, j: usize) -> bool {
+    match (O::TIE_BREAK, i <= j) {
+        (TieBreak::Leftmost, true) | (TieBreak::Rightmost, false) => !O::less(b, a),
+        (TieBreak::Leftmost, false) | (TieBreak::Rightmost, true) => O::less(a, b),
+    }
+}
+
 /// The reference "algorithm" which does nothing.
 /// We use it to determine the overhead needed to generate test cases and call queries.
 pub struct Reference<T> {
@@ -1,44 +1,72 @@
-use std::rc::Rc;
+use std::{marker::PhantomData, rc::Rc};
 
-use super::{min_index, Rmq};
+use super::{Min, Rmq, Selection, Semigroup};
 
 /// Represents a RMQ with an almost naive preprocessing.
+/// Stores the result for every range; by default, the minimum.
 /// Runtime: O(n^2) | O(1)
-pub struct Naive<T: PartialOrd> {
+pub struct Naive<T, Op: Semigroup<T> = Min> {
     data: Rc<[T]>,
-    table: Vec<Vec<usize>>,
+    table: Vec<Vec<Op::Output>>,
+    phantom_data: PhantomData<Op>,
 }
 
-impl<T: PartialOrd> Rmq<T> for Naive<T> {
-    fn new(data: Rc<[T]>) -> Self {
+impl<T, Op: Semigroup<T>> Naive<T, Op> {
+    /// Constructor.
+    pub fn new(data: Rc<[T]>) -> Self {
         Self {
             data,
             table: Vec::new(),
+            phantom_data: PhantomData,
         }
     }
 
-    fn process_data(&mut self) {
-        let n = self.data.len();
+    /// Pre-processes the data to allow queries.
+    pub fn process_data(&mut self) {
+        let data: &[T] = &self.data;
+        let n = data.len();
 
-        self.table.resize_with(n, || vec![0; n]);
+        self.table.clear();
+        self.table.resize_with(n, || Vec::with_capacity(n));
 
         // --- Compute all results. ---
 
+        // When reaching row i, rows 0 to i - 1 already added the first i
+        // entries of row i.
+
         for i in 0..n {
             // Base case.
-            self.table[i][i] = i;
+            let mut res = Op::lift(data, i);
+            self.table[i].push(res.clone());
 
             for j in (i + 1)..n {
                 // Recursive call.
-                let min_idx = min_index(&self.data, self.table[i][j - 1], j);
+                res = Op::combine(data, &res, &Op::lift(data, j));
 
-                self.table[i][j] = min_idx;
-                self.table[j][i] = min_idx;
+                self.table[i].push(res.clone());
+                self.table[j].push(res.clone());
             }
         }
     }
 
+    /// Combines all entries in the given range [i, j].
+    /// Behaviour is undefined if the given range is invalid or pre-processing
+    /// has not been done.
+    pub fn fold(&self, i: usize, j: usize) -> Op::Output {
+        self.table[i][j].clone()
+    }
+}
+
+impl<T, Op: Selection<T>> Rmq<T> for Naive<T, Op> {
+    fn new(data: Rc<[T]>) -> Self {
+        Self::new(data)
+    }
+
+    fn process_data(&mut self) {
+        self.process_data();
+    }
+
     fn query(&self, i: usize, j: usize) -> usize {
-        self.table[i][j]
+        self.fold(i, j)
     }
 }
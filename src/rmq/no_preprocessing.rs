@@ -1,17 +1,21 @@
-use std::rc::Rc;
+use std::{marker::PhantomData, rc::Rc};
 
-use super::{min_index, Rmq};
+use super::{min_index, Natural, Order, Rmq};
 
 /// Represents an RMQ without pre-processing by simply iterating over the given
 /// range.
 /// Runtime: O(1) | O(k)
-pub struct NoPreprocessing<T: PartialOrd> {
+pub struct NoPreprocessing<T, O: Order<T> = Natural> {
     data: Rc<[T]>,
+    phantom_data: PhantomData<O>,
 }
 
-impl<T: PartialOrd> Rmq<T> for NoPreprocessing<T> {
+impl<T, O: Order<T>> Rmq<T> for NoPreprocessing<T, O> {
     fn new(data: Rc<[T]>) -> Self {
-        Self { data }
+        Self {
+            data,
+            phantom_data: PhantomData,
+        }
     }
 
     fn process_data(&mut self) {
@@ -23,7 +27,7 @@ impl<T: PartialOrd> Rmq<T> for NoPreprocessing<T> {
         let mut min_idx = i;
 
         for idx in (i + 1)..=j {
-            min_idx = min_index(&self.data, min_idx, idx);
+            min_idx = min_index::<T, O>(&self.data, min_idx, idx);
         }
 
         min_idx
@@ -0,0 +1,133 @@
+use super::{min_index, Natural, Order};
+
+/// Represents the end of a list of queries.
+const NULL_QUERY: usize = usize::MAX;
+
+/// Answers a batch of range minimum queries that are all known in advance.
+/// Returns, for each query (i, j) with i <= j, the index of the minimum in
+/// that range (in the same order as the queries).
+///
+/// Uses Arpa's trick: the data is scanned from left to right while keeping
+/// the monotonic stack of all entries seen so far. Each entry is linked
+/// (union-find) to the entry which removed it from the stack. After reaching
+/// index j, the minimum in [i, j] is the stack entry owning the set of i.
+/// Sets are united by size and paths are compressed.
+/// Runtime: O((n + q) α(n))
+pub fn offline_rmq<T: PartialOrd>(data: &[T], queries: &[(usize, usize)]) -> Vec<usize> {
+    offline_rmq_by::<T, Natural>(data, queries)
+}
+
+/// Answers a batch of range minimum queries with respect to the given order.
+/// See `offline_rmq()`.
+pub fn offline_rmq_by<T, O: Order<T>>(data: &[T], queries: &[(usize, usize)]) -> Vec<usize> {
+    let n = data.len();
+
+    // --- Group queries by their right index. ---
+
+    // Each group is a linked list: first[j] is the first query ending at j,
+    // next[q] the query after q.
+    let mut first = vec![NULL_QUERY; n];
+    let mut next = vec![NULL_QUERY; queries.len()];
+
+    for (q, &(_, j)) in queries.iter().enumerate() {
+        next[q] = first[j];
+        first[j] = q;
+    }
+
+    // --- Scan data. ---
+
+    let mut result = vec![0; queries.len()];
+
+    let mut sets = DisjointSets::new(n);
+    let mut stack = Vec::new();
+
+    for (j, &first_q) in first.iter().enumerate() {
+        // Remove all entries that are not preferred over data[j] as minimum.
+        // They cannot be the minimum of any range ending at j or later.
+        while let Some(&top) = stack.last() {
+            if min_index::<T, O>(data, top, j) == top {
+                break;
+            }
+
+            sets.unite(top, j);
+            stack.pop();
+        }
+
+        stack.push(j);
+
+        // Answer queries ending at j.
+        let mut q = first_q;
+        while q != NULL_QUERY {
+            result[q] = sets.label(queries[q].0);
+            q = next[q];
+        }
+    }
+
+    result
+}
+
+/// Represents disjoint sets of indices. Each set is owned by the stack entry
+/// which removed all other entries of the set from the stack.
+struct DisjointSets {
+    /// The parent of each index in its set's tree. Roots are their own parent.
+    parent: Vec<usize>,
+
+    /// The number of indices in the set of each root.
+    size: Vec<usize>,
+
+    /// The index owning the set of each root.
+    label: Vec<usize>,
+}
+
+impl DisjointSets {
+    /// Creates n sets, each containing and owned by a single index.
+    fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            label: (0..n).collect(),
+        }
+    }
+
+    /// Unites the sets of a and b. The new set is owned by the owner of b's
+    /// set.
+    fn unite(&mut self, a: usize, b: usize) {
+        let mut a_root = self.root(a);
+        let mut b_root = self.root(b);
+        let owner = self.label[b_root];
+
+        // Union by size.
+        if self.size[a_root] > self.size[b_root] {
+            std::mem::swap(&mut a_root, &mut b_root);
+        }
+
+        self.parent[a_root] = b_root;
+        self.size[b_root] += self.size[a_root];
+        self.label[b_root] = owner;
+    }
+
+    /// Returns the index owning the set of the given index.
+    #[inline]
+    fn label(&mut self, idx: usize) -> usize {
+        let root = self.root(idx);
+        self.label[root]
+    }
+
+    /// Determines the root of the given index and compresses its path.
+    fn root(&mut self, idx: usize) -> usize {
+        let mut root = idx;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // Full path compression.
+        let mut idx = idx;
+        while self.parent[idx] != root {
+            let next = self.parent[idx];
+            self.parent[idx] = root;
+            idx = next;
+        }
+
+        root
+    }
+}
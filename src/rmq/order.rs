@@ -0,0 +1,117 @@
+//! Defines orders by which RMQ algorithms compare entries.
+//!
+//! Orders are types instead of values so that algorithms can still be created
+//! with `Rmq::new()`. For example, `SparseTable<T, Min<Reverse>>` and
+//! `NoPreprocessing<T, Reverse>` answer range maximum queries.
+//!
+//! Likewise, a comparator or key-extraction function is a type implementing
+//! `Order` or `Key`, not a closure or comparator value. It can only depend on
+//! the values themselves, for example a field of a record (see `ByKey`). Keys
+//! that depend on state at runtime (e.g., a captured lookup table) cannot be
+//! expressed; such data still needs a separate list of keys.
+//!
+//! An order also states which index is reported if several entries of a range
+//! are minimal (see `TieBreak`). Unless changed with `Leftmost`, it is the
+//! right-most one. All algorithms honour that policy; that is, they all return
+//! the same index for the same query.
+
+use std::marker::PhantomData;
+
+/// States which index is reported if several entries of a range are minimal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TieBreak {
+    /// The smallest index among all minimal entries.
+    Leftmost,
+
+    /// The largest index among all minimal entries.
+    Rightmost,
+}
+
+/// Represents a strict order over values of type `T`.
+pub trait Order<T> {
+    /// The index reported if several entries are minimal.
+    const TIE_BREAK: TieBreak = TieBreak::Rightmost;
+
+    /// Determines if `a` is smaller than `b` with respect to this order.
+    fn less(a: &T, b: &T) -> bool;
+}
+
+/// Uses the given order, but reports the left-most of all minimal entries.
+pub struct Leftmost<O = Natural>(PhantomData<O>);
+
+impl<T, O: Order<T>> Order<T> for Leftmost<O> {
+    const TIE_BREAK: TieBreak = TieBreak::Leftmost;
+
+    #[inline(always)]
+    fn less(a: &T, b: &T) -> bool {
+        O::less(a, b)
+    }
+}
+
+/// Uses the given order, but reports the right-most of all minimal entries.
+pub struct Rightmost<O = Natural>(PhantomData<O>);
+
+impl<T, O: Order<T>> Order<T> for Rightmost<O> {
+    const TIE_BREAK: TieBreak = TieBreak::Rightmost;
+
+    #[inline(always)]
+    fn less(a: &T, b: &T) -> bool {
+        O::less(a, b)
+    }
+}
+
+/// The order given by `PartialOrd`.
+pub struct Natural;
+
+impl<T: PartialOrd> Order<T> for Natural {
+    #[inline(always)]
+    fn less(a: &T, b: &T) -> bool {
+        a < b
+    }
+}
+
+/// The reverse of the given order. With it, RMQ algorithms find maxima.
+/// Keeps the tie-breaking policy of the given order.
+pub struct Reverse<O = Natural>(PhantomData<O>);
+
+impl<T, O: Order<T>> Order<T> for Reverse<O> {
+    const TIE_BREAK: TieBreak = O::TIE_BREAK;
+
+    #[inline(always)]
+    fn less(a: &T, b: &T) -> bool {
+        O::less(b, a)
+    }
+}
+
+/// Represents a function that extracts a key from a value, for example one of
+/// its fields.
+pub trait Key<T> {
+    /// The type of the keys.
+    type Key: PartialOrd;
+
+    /// Returns the key of the given value.
+    fn key(value: &T) -> Self::Key;
+}
+
+/// Orders values by the keys the given function extracts.
+pub struct ByKey<K>(PhantomData<K>);
+
+impl<T, K: Key<T>> Order<T> for ByKey<K> {
+    #[inline(always)]
+    fn less(a: &T, b: &T) -> bool {
+        K::key(a) < K::key(b)
+    }
+}
+
+/// Orders values lexicographically: first by order `A` and, if neither value
+/// is smaller, by order `B`. Keeps the tie-breaking policy of `A`.
+pub struct Then<A, B>(PhantomData<(A, B)>);
+
+impl<T, A: Order<T>, B: Order<T>> Order<T> for Then<A, B> {
+    const TIE_BREAK: TieBreak = A::TIE_BREAK;
+
+    #[inline(always)]
+    fn less(a: &T, b: &T) -> bool {
+        A::less(a, b) || (!A::less(b, a) && B::less(a, b))
+    }
+}
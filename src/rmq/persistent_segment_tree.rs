@@ -0,0 +1,190 @@
+use std::{marker::PhantomData, rc::Rc};
+
+use super::{
+    is_min,
+    segment_tree::{build_tree, leaf_path, Node},
+    Natural, Order, Rmq, RmqUpdate,
+};
+
+/// Represents a RMQ that uses a persistent segment tree to run queries on
+/// earlier versions of the data.
+/// Each update copies the path from the root to the changed leaf and shares
+/// all other nodes with the version it is based on. Version 0 is the original
+/// data.
+/// Runtime: O(n) | O(log n), updates O(log n) time and space
+pub struct PersistentSegmentTree<T, O: Order<T> = Natural> {
+    /// The original data. Not changed by updates.
+    data: Rc<[T]>,
+
+    /// The nodes of all versions. Each node stores the minimum (value and
+    /// index) of its covered range.
+    tree: Vec<Node<(T, usize)>>,
+
+    /// The index of each version's root in tree[].
+    roots: Vec<usize>,
+
+    phantom_data: PhantomData<O>,
+}
+
+impl<T: Clone, O: Order<T>> PersistentSegmentTree<T, O> {
+    /// Constructor.
+    pub fn new(data: Rc<[T]>) -> Self {
+        Self {
+            data,
+            tree: Vec::new(),
+            roots: Vec::new(),
+            phantom_data: PhantomData,
+        }
+    }
+
+    /// Pre-processes the data to allow queries.
+    /// Removes all versions except the original data.
+    pub fn process_data(&mut self) {
+        let data: &[T] = &self.data;
+
+        self.tree = build_tree(
+            data.len(),
+            |i| (data[i].clone(), i),
+            |a, b| Self::combine(a, b).clone(),
+        );
+
+        self.roots.clear();
+        self.roots.push(0);
+    }
+
+    /// Returns the number of versions.
+    pub fn versions(&self) -> usize {
+        self.roots.len()
+    }
+
+    /// Performs a query on the given range of the given version.
+    /// Returns the index of the minimum in that range.
+    /// Behaviour is undefined if the given range or version is invalid or
+    /// pre-processing has not been done.
+    pub fn query_version(&self, version: usize, i: usize, j: usize) -> usize {
+        self.query_node(self.roots[version], i, j).unwrap().1
+    }
+
+    /// Returns the value of the entry at the given index in the given version.
+    /// Behaviour is undefined if the given version is invalid or
+    /// pre-processing has not been done.
+    pub fn get(&self, version: usize, idx: usize) -> T {
+        self.query_node(self.roots[version], idx, idx).unwrap().0
+    }
+
+    /// Creates a new version from the given one in which the entry at the
+    /// given index is replaced with the given value.
+    /// Returns the new version.
+    /// Behaviour is undefined if the given version is invalid or
+    /// pre-processing has not been done.
+    pub fn update(&mut self, version: usize, idx: usize, value: T) -> usize {
+        let mut path = leaf_path(&self.tree, self.roots[version], idx);
+
+        // Copy nodes bottom-up. Each copy points to the copy of its child on
+        // the path.
+        let mut child_idx = path.pop().unwrap();
+        let mut copy_idx = self.copy_node(child_idx, (value, idx));
+
+        while let Some(node_idx) = path.pop() {
+            let node = &self.tree[node_idx];
+            let (mut left, mut right) = (node.left, node.right);
+
+            if left == child_idx {
+                left = copy_idx;
+            } else {
+                right = copy_idx;
+            }
+
+            let value = if right == usize::MAX {
+                self.tree[left].value.clone()
+            } else {
+                Self::combine(&self.tree[left].value, &self.tree[right].value).clone()
+            };
+
+            child_idx = node_idx;
+            copy_idx = self.copy_node(node_idx, value);
+            self.tree[copy_idx].left = left;
+            self.tree[copy_idx].right = right;
+        }
+
+        self.roots.push(copy_idx);
+        self.roots.len() - 1
+    }
+
+    /// Appends a copy of the given node with the given value to tree[].
+    /// Returns the index of the copy.
+    fn copy_node(&mut self, node_idx: usize, value: (T, usize)) -> usize {
+        let node = &self.tree[node_idx];
+
+        self.tree.push(Node {
+            left: node.left,
+            right: node.right,
+            fr_idx: node.fr_idx,
+            to_idx: node.to_idx,
+            value,
+        });
+
+        self.tree.len() - 1
+    }
+
+    /// Determines the minimum (value and index) of the entries of the given
+    /// node that are in the range [i, j]. Returns None if there are none.
+    fn query_node(&self, node_idx: usize, i: usize, j: usize) -> Option<(T, usize)> {
+        let node = &self.tree[node_idx];
+
+        if j < node.fr_idx || node.to_idx < i {
+            // Disjoint ranges.
+            return None;
+        }
+
+        if i <= node.fr_idx && node.to_idx <= j {
+            // Node is covered completely.
+            return Some(node.value.clone());
+        }
+
+        let l_min = self.query_node(node.left, i, j);
+        if node.right == usize::MAX {
+            return l_min;
+        }
+        let r_min = self.query_node(node.right, i, j);
+
+        match (l_min, r_min) {
+            (Some(l), Some(r)) => Some(Self::combine(&l, &r).clone()),
+            (l, None) => l,
+            (None, r) => r,
+        }
+    }
+
+    /// Determines which of the given minima (value and index) is the minimum
+    /// of both.
+    #[inline]
+    fn combine<'a>(a: &'a (T, usize), b: &'a (T, usize)) -> &'a (T, usize) {
+        if is_min::<T, O>(&a.0, a.1, &b.0, b.1) {
+            a
+        } else {
+            b
+        }
+    }
+}
+
+impl<T: Clone, O: Order<T>> Rmq<T> for PersistentSegmentTree<T, O> {
+    fn new(data: Rc<[T]>) -> Self {
+        Self::new(data)
+    }
+
+    fn process_data(&mut self) {
+        self.process_data();
+    }
+
+    /// Performs a query on the latest version.
+    fn query(&self, i: usize, j: usize) -> usize {
+        self.query_version(self.roots.len() - 1, i, j)
+    }
+}
+
+impl<T: Clone, O: Order<T>> RmqUpdate<T> for PersistentSegmentTree<T, O> {
+    /// Creates a new version from the latest one.
+    fn update(&mut self, idx: usize, value: T) {
+        self.update(self.roots.len() - 1, idx, value);
+    }
+}
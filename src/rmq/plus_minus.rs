@@ -2,13 +2,13 @@ use std::rc::Rc;
 
 use crate::log::log_f;
 
-use super::{min_index, Rmq, SparseTable};
+use super::{min_index, Min, Natural, Order, Rmq, SparseTable};
 
 /// Represents an RMQ algorithm for sequences that satisfy +-1 property.
 /// A sequence [x_1, x_2, ..., x_n] satisfies that property if, for all i < n,
 /// |x_i - x_{i + 1}| = 1. That is, consecutive elements differ by exactly 1.
 /// Runtime: O(n) | O(1)
-pub struct PlusMinus<T> {
+pub struct PlusMinus<T, O: Order<T> = Natural> {
     data: Rc<[T]>,
 
     /// States how large a single block of the data is.
@@ -24,17 +24,17 @@ pub struct PlusMinus<T> {
     block_min_idx: Vec<usize>,
 
     /// A RMQ to find the minimum block.
-    table_rmq: SparseTable<T>,
+    table_rmq: SparseTable<T, Min<O>>,
 
     /// States for each block, what class it is.
     block_cls: Vec<usize>,
 
     // ToDo: Why is this a pointer?
     /// Allows to determine the minimum in a single block.
-    class_rmq: Vec<Option<SparseTable<T>>>,
+    class_rmq: Vec<Option<SparseTable<T, Min<O>>>>,
 }
 
-impl<T: PartialOrd + Copy> Rmq<T> for PlusMinus<T> {
+impl<T: Copy, O: Order<T>> Rmq<T> for PlusMinus<T, O> {
     fn new(data: Rc<[T]>) -> Self {
         Self {
             data,
@@ -60,9 +60,10 @@ impl<T: PartialOrd + Copy> Rmq<T> for PlusMinus<T> {
             //     2^k in [1/2 log n, 1/4 log n).
             // Note that this is equivalent to
             //     2^{k + 1} in [log n, 1/2 log n).
+            // Very short data (n < 16) has blocks of size 1.
 
             let log_n = log_f(n);
-            let k = log_f(log_n) - 1;
+            let k = log_f(log_n).saturating_sub(1);
 
             self.block_size = 1 << k;
 
@@ -97,7 +98,7 @@ impl<T: PartialOrd + Copy> Rmq<T> for PlusMinus<T> {
                 while j < self.block_size && i < n {
                     // j: The current index in the current block.
 
-                    if data[i] < *cur_min {
+                    if min_index::<T, O>(data, *cur_idx, i) == i {
                         *cur_min = data[i];
                         *cur_idx = i;
                     }
@@ -138,11 +139,16 @@ impl<T: PartialOrd + Copy> Rmq<T> for PlusMinus<T> {
 
                 // Determine if it is +1 (0) or -1 (1).
                 cls <<= 1;
-                if a >= b {
+                if !O::less(&a, &b) {
                     cls |= 1
                 }
             }
 
+            // A shorter (i.e. the last) block has fewer bits. Align them with
+            // the bits of a full block such that it shares the class of full
+            // blocks starting with the same sequence.
+            cls <<= self.block_size - (b_end - b_sta);
+
             self.block_cls[b] = cls;
 
             // Has that class an RMQ?
@@ -175,7 +181,7 @@ impl<T: PartialOrd + Copy> Rmq<T> for PlusMinus<T> {
 
         let i_min = self.in_block_min(i_b, i_idx, self.block_mod);
         let j_min = self.in_block_min(j_b, 0, j_idx);
-        let ij_min = min_index(&self.data, i_min, j_min);
+        let ij_min = min_index::<T, O>(&self.data, i_min, j_min);
 
         // Are blocks adjacent?
         if i_b + 1 == j_b {
@@ -186,11 +192,11 @@ impl<T: PartialOrd + Copy> Rmq<T> for PlusMinus<T> {
         let b_idx = self.table_rmq.query(i_b + 1, j_b - 1);
         let b_min = self.block_min_idx[b_idx];
 
-        min_index(&self.data, ij_min, b_min)
+        min_index::<T, O>(&self.data, ij_min, b_min)
     }
 }
 
-impl<T: PartialOrd> PlusMinus<T> {
+impl<T, O: Order<T>> PlusMinus<T, O> {
     /// Performs a query on the given block and given range.
     /// Returns the index of the minimum entry in that range with respect to the
     /// original data.
@@ -1,198 +1,405 @@
-use std::rc::Rc;
+use std::{marker::PhantomData, rc::Rc};
 
-use super::{min_index, Rmq};
+use super::{semigroup::min_excluding, Min, MinTwo, Order, Rmq, RmqUpdate, Selection, Semigroup};
 
 /// Represents a node of a SegmentTree.
-struct Node {
+#[derive(Clone)]
+pub(super) struct Node<V> {
     /// The index of the node's left child.
-    left: usize,
+    pub(super) left: usize,
 
     /// The index of the node's right child.
-    right: usize,
+    pub(super) right: usize,
 
     /// The first index (inclusive) of the node's covered range.
-    fr_idx: usize,
+    pub(super) fr_idx: usize,
 
     /// The last index (inclusive) of the node's covered range.
-    to_idx: usize,
+    pub(super) to_idx: usize,
 
-    /// The index in the node's covered range with minimum value in that range.
-    min_idx: usize,
+    /// The result for the node's covered range.
+    pub(super) value: V,
 }
 
-impl Node {
-    fn new() -> Self {
+impl<V> Node<V> {
+    fn new(fr_idx: usize, to_idx: usize, value: V) -> Self {
         Self {
             left: usize::MAX,
             right: usize::MAX,
-            fr_idx: 0,
-            to_idx: 0,
-            min_idx: 0,
+            fr_idx,
+            to_idx,
+            value,
         }
     }
 }
 
 /// Represents a RMQ that uses a segment tree to run queries.
+/// Works for any associative operation; by default, the minimum.
+/// Allows to update single entries in O(log n).
 /// Runtime: O(n) | O(log n)
-pub struct SegmentTree<T: PartialOrd> {
-    data: Rc<[T]>,
-    tree: Vec<Node>,
+pub struct SegmentTree<T, Op: Semigroup<T> = Min> {
+    /// A copy of the data, which is changed by updates.
+    data: Vec<T>,
+    tree: Vec<Node<Op::Output>>,
+    phantom_data: PhantomData<Op>,
 }
 
-impl<T: PartialOrd> Rmq<T> for SegmentTree<T> {
-    fn new(data: Rc<[T]>) -> Self {
+impl<T: Clone, Op: Semigroup<T>> SegmentTree<T, Op> {
+    /// Constructor.
+    pub fn new(data: Rc<[T]>) -> Self {
         Self {
-            data,
+            data: data.to_vec(),
             tree: Vec::new(),
+            phantom_data: PhantomData,
         }
     }
+}
 
-    fn process_data(&mut self) {
-        let n = self.data.len();
+impl<T, Op: Semigroup<T>> SegmentTree<T, Op> {
+    /// Pre-processes the data to allow queries.
+    pub fn process_data(&mut self) {
+        let data: &[T] = &self.data;
+
+        self.tree = build_tree(
+            data.len(),
+            |i| Op::lift(data, i),
+            |a, b| Op::combine(data, a, b),
+        );
+    }
 
-        //
-        // --- Build tree. ---
+    /// Combines all entries in the given range [i, j].
+    /// Behaviour is undefined if the given range is invalid or pre-processing
+    /// has not been done.
+    pub fn fold(&self, i: usize, j: usize) -> Op::Output {
+        let data: &[T] = &self.data;
 
-        // Determine size.
-        let mut tree_size = n;
-        let mut lay_sz = n;
-        while lay_sz > 1 {
-            lay_sz = (lay_sz + 1) >> 1;
-            tree_size += lay_sz
+        fold_tree(&self.tree, i, j, Clone::clone, |a, b| {
+            Op::combine(data, a, b)
+        })
+    }
+
+    /// Replaces the entry at the given index with the given value and repairs
+    /// all nodes covering it.
+    /// Behaviour is undefined if pre-processing has not been done.
+    pub fn update(&mut self, idx: usize, value: T) {
+        self.data[idx] = value;
+
+        let data: &[T] = &self.data;
+        update_tree(&mut self.tree, idx, Op::lift(data, idx), |a, b| {
+            Op::combine(data, a, b)
+        });
+    }
+}
+
+impl<T, O: Order<T>> SegmentTree<T, Min<O>> {
+    /// Determines the first index in the range [i, j] whose entry is smaller
+    /// than x with respect to the order. Returns None if there is none.
+    /// Skips all nodes whose minimum is not smaller than x.
+    /// Behaviour is undefined if the given range is invalid or pre-processing
+    /// has not been done.
+    /// Runtime: O(log n)
+    pub fn find_first_below(&self, i: usize, j: usize, x: &T) -> Option<usize> {
+        self.find_below(0, i, j, x, true)
+    }
+
+    /// Determines the last index in the range [i, j] whose entry is smaller
+    /// than x with respect to the order. Returns None if there is none.
+    /// See `find_first_below()`.
+    pub fn find_last_below(&self, i: usize, j: usize, x: &T) -> Option<usize> {
+        self.find_below(0, i, j, x, false)
+    }
+
+    /// Determines the first (or last) index of the given node in the range
+    /// [i, j] whose entry is smaller than x.
+    fn find_below(&self, node_idx: usize, i: usize, j: usize, x: &T, first: bool) -> Option<usize> {
+        let node = &self.tree[node_idx];
+
+        if j < node.fr_idx || node.to_idx < i || !O::less(&self.data[node.value], x) {
+            // Disjoint ranges or no entry is small enough.
+            return None;
         }
 
-        self.tree.resize_with(tree_size, Node::new);
+        if node.left == usize::MAX {
+            // Leaf reached.
+            return Some(node.fr_idx);
+        }
 
-        // Build bottom layer.
-        for i in 0..n {
-            let node = &mut self.tree[tree_size - n + i];
-            node.min_idx = i;
-            node.fr_idx = i;
-            node.to_idx = i;
+        if node.right == usize::MAX {
+            return self.find_below(node.left, i, j, x, first);
         }
 
-        // Build tree bottom-up.
-        let mut q_size = n;
-        let mut q_start = tree_size - n;
-        while q_size > 1 {
-            // Outer loop has one iteration per layer.
+        let (a, b) = if first {
+            (node.left, node.right)
+        } else {
+            (node.right, node.left)
+        };
+
+        self.find_below(a, i, j, x, first)
+            .or_else(|| self.find_below(b, i, j, x, first))
+    }
+}
+
+impl<T, O: Order<T>> SegmentTree<T, MinTwo<O>> {
+    /// Performs a query on the given range while ignoring the entry at the
+    /// given index. Returns the index of the minimum of the remaining entries,
+    /// or None if there are none.
+    /// Behaviour is undefined if the given range is invalid or pre-processing
+    /// has not been done.
+    pub fn query_excluding(&self, i: usize, j: usize, idx: usize) -> Option<usize> {
+        min_excluding(self.fold(i, j), idx)
+    }
+}
+
+impl<T: Clone, Op: Selection<T>> Rmq<T> for SegmentTree<T, Op> {
+    fn new(data: Rc<[T]>) -> Self {
+        Self::new(data)
+    }
+
+    fn process_data(&mut self) {
+        self.process_data();
+    }
+
+    fn query(&self, i: usize, j: usize) -> usize {
+        self.fold(i, j)
+    }
+}
+
+impl<T: Clone, Op: Selection<T>> RmqUpdate<T> for SegmentTree<T, Op> {
+    fn update(&mut self, idx: usize, value: T) {
+        self.update(idx, value);
+    }
+}
 
-            //  q_size: The number of nodes in the previous layer.
-            // q_start: The index of the first (i.e. left-most) node of the
-            //          previous layer in tree[].
+/// Builds the nodes of a segment tree over n entries. The value of a leaf is
+/// determined by lift, the value of an inner node by combining the values of
+/// its children.
+/// The nodes are stored top-down, i.e. the root is at index 0.
+pub(super) fn build_tree<V, L, C>(n: usize, mut lift: L, mut combine: C) -> Vec<Node<V>>
+where
+    V: Clone,
+    L: FnMut(usize) -> V,
+    C: FnMut(&V, &V) -> V,
+{
+    // Determine size.
+    let mut tree_size = n;
+    let mut lay_sz = n;
+    while lay_sz > 1 {
+        lay_sz = (lay_sz + 1) >> 1;
+        tree_size += lay_sz
+    }
 
-            let (upper_layers, lower_layers) = self.tree.split_at_mut(q_start);
+    // The tree is built bottom-up. Nodes are pushed from right to left, i.e.
+    // tree[] holds them in reverse order until it is reversed at the end. The
+    // node with index x in the final tree is at tree[tree_size - 1 - x].
+    let mut tree = Vec::with_capacity(tree_size);
 
-            let cur_lay_size = (q_size + 1) >> 1;
+    // Build bottom layer.
+    tree.extend((0..n).rev().map(|i| Node::new(i, i, lift(i))));
 
-            let mut n_ptr = q_start - cur_lay_size;
-            let mut q_ptr = 0;
-            while q_ptr < q_size {
-                // The inner loop iterates over the nodes in the layer below and
-                // "creates" the nodes of the current layer.
+    // Build tree bottom-up.
+    let mut q_size = n;
+    let mut q_start = tree_size - n;
+    while q_size > 1 {
+        // Outer loop has one iteration per layer.
 
-                // n_ptr: The index in upper_layers[] of the "new" node.
-                // q_ptr: The index in lower_layers[] of the new node's first child.
+        //  q_size: The number of nodes in the previous layer.
+        // q_start: The index of the first (i.e. left-most) node of the
+        //          previous layer in the final tree.
 
-                let left_node = &lower_layers[q_ptr];
+        let cur_lay_size = (q_size + 1) >> 1;
 
-                let node = &mut upper_layers[n_ptr];
-                node.left = q_start + q_ptr;
-                node.fr_idx = left_node.fr_idx;
-                node.to_idx = left_node.to_idx;
-                node.min_idx = left_node.min_idx;
+        for n_ptr in (0..cur_lay_size).rev() {
+            // The inner loop iterates over the nodes of the current layer
+            // from right to left and "creates" them from the layer below.
 
-                // Still one more element?
-                if q_ptr + 1 < q_size {
-                    let right_node = &lower_layers[q_ptr + 1];
-                    node.right = q_start + q_ptr + 1;
-                    node.to_idx = right_node.to_idx;
-                    node.min_idx = min_index(&self.data, node.min_idx, right_node.min_idx);
-                }
+            // n_ptr: The index of the new node in the current layer.
 
-                n_ptr += 1;
-                q_ptr += 2;
-            }
+            let l_idx = q_start + 2 * n_ptr;
+            let left_node = &tree[tree_size - 1 - l_idx];
 
-            q_size = cur_lay_size;
-            q_start -= q_size;
+            // Still one more element?
+            let mut node = if 2 * n_ptr + 1 < q_size {
+                let right_node = &tree[tree_size - 2 - l_idx];
+                let value = combine(&left_node.value, &right_node.value);
+
+                let mut node = Node::new(left_node.fr_idx, right_node.to_idx, value);
+                node.right = l_idx + 1;
+                node
+            } else {
+                Node::new(left_node.fr_idx, left_node.to_idx, left_node.value.clone())
+            };
+
+            node.left = l_idx;
+            tree.push(node);
         }
+
+        q_size = cur_lay_size;
+        q_start -= q_size;
     }
 
-    fn query(&self, i: usize, j: usize) -> usize {
-        let mut min_idx = i;
-        let mut node_idx = 0;
-
-        // Go down until paths to i and j split.
-        loop {
-            let node = &self.tree[node_idx];
-
-            if node.fr_idx == i && node.to_idx == j {
-                // Base case.
-                return node.min_idx;
-            }
-
-            let left_to = self.tree[node.left].to_idx;
-
-            if j <= left_to {
-                // Go left.
-                node_idx = node.left;
-            } else if i > left_to {
-                // Go right.
-                node_idx = node.right;
-            } else {
-                // Split paths.
-                break;
-            }
+    tree.reverse();
+    tree
+}
+
+/// Combines the values of the nodes covering the range [i, j] of the given
+/// tree from left to right. Each value is first mapped into the result type.
+pub(super) fn fold_tree<V, R, M, C>(
+    tree: &[Node<V>],
+    i: usize,
+    j: usize,
+    mut map: M,
+    mut combine: C,
+) -> R
+where
+    M: FnMut(&V) -> R,
+    C: FnMut(&R, &R) -> R,
+{
+    let mut node_idx = 0;
+
+    // Go down until paths to i and j split.
+    loop {
+        let node = &tree[node_idx];
+
+        if node.fr_idx == i && node.to_idx == j {
+            // Base case.
+            return map(&node.value);
         }
 
-        // Go down left and search for i.
-        let mut i_node_idx = self.tree[node_idx].left;
-        loop {
-            let i_node = &self.tree[i_node_idx];
+        let left_to = tree[node.left].to_idx;
+
+        if j <= left_to {
+            // Go left.
+            node_idx = node.left;
+        } else if i > left_to {
+            // Go right.
+            node_idx = node.right;
+        } else {
+            // Split paths.
+            break;
+        }
+    }
 
-            if i_node.fr_idx == i {
-                // Base case.
-                min_idx = min_index(&self.data, min_idx, i_node.min_idx);
-                break;
-            }
+    // Go down left and search for i.
+    // Nodes found later are further left.
+    let mut i_res = None;
+    let mut i_node_idx = tree[node_idx].left;
+    loop {
+        let i_node = &tree[i_node_idx];
+
+        if i_node.fr_idx == i {
+            // Base case.
+            i_res = Some(prepend(map(&i_node.value), i_res, &mut combine));
+            break;
+        }
 
-            if i <= self.tree[i_node.left].to_idx {
-                // Get minimum from right node ...
-                min_idx = min_index(&self.data, min_idx, self.tree[i_node.right].min_idx);
+        if i <= tree[i_node.left].to_idx {
+            // Get result from right node ...
+            let r_res = map(&tree[i_node.right].value);
+            i_res = Some(prepend(r_res, i_res, &mut combine));
 
-                // ... and go left.
-                i_node_idx = i_node.left;
-            } else {
-                // Go right.
-                i_node_idx = i_node.right;
-            }
+            // ... and go left.
+            i_node_idx = i_node.left;
+        } else {
+            // Go right.
+            i_node_idx = i_node.right;
         }
+    }
 
-        // Go down right and search for j.
-        let mut j_node_idx = self.tree[node_idx].right;
-        loop {
-            let j_node = &self.tree[j_node_idx];
-
-            if j_node.to_idx == j {
-                // Base case.
-                min_idx = min_index(&self.data, min_idx, j_node.min_idx);
-                break;
-            }
-
-            let left_child = &self.tree[j_node.left];
-            if j <= left_child.to_idx {
-                // Go left.
-                j_node_idx = j_node.left;
-            } else {
-                // Get minimum from left node ...
-                min_idx = min_index(&self.data, min_idx, left_child.min_idx);
+    // Go down right and search for j.
+    // Nodes found later are further right.
+    let mut j_res = None;
+    let mut j_node_idx = tree[node_idx].right;
+    loop {
+        let j_node = &tree[j_node_idx];
+
+        if j_node.to_idx == j {
+            // Base case.
+            j_res = Some(append(j_res, map(&j_node.value), &mut combine));
+            break;
+        }
+
+        let left_child = &tree[j_node.left];
+        if j <= left_child.to_idx {
+            // Go left.
+            j_node_idx = j_node.left;
+        } else {
+            // Get result from left node ...
+            j_res = Some(append(j_res, map(&left_child.value), &mut combine));
 
-                // ... and go right.
-                j_node_idx = j_node.right;
-            }
+            // ... and go right.
+            j_node_idx = j_node.right;
         }
+    }
+
+    // Both paths end in a base case.
+    combine(&i_res.unwrap(), &j_res.unwrap())
+}
+
+/// Replaces the value of the leaf of the given index with the given value and
+/// repairs all nodes covering it.
+pub(super) fn update_tree<V, C>(tree: &mut [Node<V>], idx: usize, value: V, mut combine: C)
+where
+    V: Clone,
+    C: FnMut(&V, &V) -> V,
+{
+    let mut path = leaf_path(tree, 0, idx);
+
+    // Repair nodes bottom-up.
+    let leaf_idx = path.pop().unwrap();
+    tree[leaf_idx].value = value;
+
+    while let Some(node_idx) = path.pop() {
+        let node = &tree[node_idx];
+        let left_node = &tree[node.left];
+
+        let value = if node.right == usize::MAX {
+            left_node.value.clone()
+        } else {
+            combine(&left_node.value, &tree[node.right].value)
+        };
+
+        tree[node_idx].value = value;
+    }
+}
+
+/// Returns the indices of all nodes on the path from the given root down to
+/// the leaf of the given index, starting with the root.
+pub(super) fn leaf_path<V>(tree: &[Node<V>], root: usize, idx: usize) -> Vec<usize> {
+    let mut path = Vec::new();
+    let mut node_idx = root;
+    loop {
+        path.push(node_idx);
+
+        let node = &tree[node_idx];
+        if node.left == usize::MAX {
+            // Leaf reached.
+            return path;
+        }
+
+        if idx <= tree[node.left].to_idx {
+            node_idx = node.left;
+        } else {
+            node_idx = node.right;
+        }
+    }
+}
+
+/// Combines the given result with the (optional) result of the range to its
+/// right.
+#[inline]
+fn prepend<R, C: FnMut(&R, &R) -> R>(a: R, b: Option<R>, combine: &mut C) -> R {
+    match b {
+        Some(b) => combine(&a, &b),
+        None => a,
+    }
+}
 
-        min_idx
+/// Combines the given result with the (optional) result of the range to its
+/// left.
+#[inline]
+fn append<R, C: FnMut(&R, &R) -> R>(a: Option<R>, b: R, combine: &mut C) -> R {
+    match a {
+        Some(a) => combine(&a, &b),
+        None => b,
     }
 }
@@ -0,0 +1,138 @@
+use std::{marker::PhantomData, rc::Rc};
+
+use super::{
+    segment_tree::{build_tree, fold_tree, leaf_path, update_tree, Node},
+    Min, Selection, Semigroup,
+};
+
+/// Represents a RMQ that uses a segment tree over the rows of a matrix. Each
+/// node stores a segment tree over the columns which combines the rows the
+/// node covers.
+/// Works for any associative and commutative operation; by default, the
+/// minimum. Allows to update single entries in O(log n log m).
+/// The data is a matrix stored row by row. Operations refer to entries by
+/// their index in that order.
+/// Runtime: O(nm) | O(log n log m)
+pub struct SegmentTree2D<T, Op: Semigroup<T> = Min> {
+    /// A copy of the data, which is changed by updates.
+    data: Vec<T>,
+
+    /// The number of columns of the matrix.
+    cols: usize,
+
+    tree: Vec<Node<Vec<Node<Op::Output>>>>,
+    phantom_data: PhantomData<Op>,
+}
+
+impl<T: Clone, Op: Semigroup<T>> SegmentTree2D<T, Op> {
+    /// Constructor. The length of the data has to be a multiple of the number
+    /// of columns.
+    /// Panics if there are no columns or the length is not such a multiple.
+    pub fn new(data: Rc<[T]>, cols: usize) -> Self {
+        assert!(
+            cols > 0 && data.len().is_multiple_of(cols),
+            "data of length {} cannot be split into rows of {} columns",
+            data.len(),
+            cols
+        );
+
+        Self {
+            data: data.to_vec(),
+            cols,
+            tree: Vec::new(),
+            phantom_data: PhantomData,
+        }
+    }
+}
+
+impl<T, Op: Semigroup<T>> SegmentTree2D<T, Op> {
+    /// Pre-processes the data to allow queries.
+    pub fn process_data(&mut self) {
+        let data: &[T] = &self.data;
+        let cols = self.cols;
+        let combine = |a: &Op::Output, b: &Op::Output| Op::combine(data, a, b);
+
+        self.tree = build_tree(
+            data.len() / cols,
+            |r| build_tree(cols, |c| Op::lift(data, r * cols + c), combine),
+            |a, b| {
+                build_tree(
+                    cols,
+                    |c| combine(Self::leaf(a, cols, c), Self::leaf(b, cols, c)),
+                    combine,
+                )
+            },
+        );
+    }
+
+    /// Combines all entries in the given rectangle [r1, r2] x [c1, c2].
+    /// Behaviour is undefined if the given rectangle is invalid or
+    /// pre-processing has not been done.
+    pub fn fold(&self, r1: usize, r2: usize, c1: usize, c2: usize) -> Op::Output {
+        let data: &[T] = &self.data;
+        let combine = |a: &Op::Output, b: &Op::Output| Op::combine(data, a, b);
+
+        fold_tree(
+            &self.tree,
+            r1,
+            r2,
+            |col_tree| fold_tree(col_tree, c1, c2, Clone::clone, combine),
+            combine,
+        )
+    }
+
+    /// Replaces the entry in the given row and column with the given value and
+    /// repairs all nodes covering it.
+    /// Behaviour is undefined if pre-processing has not been done.
+    pub fn update(&mut self, row: usize, col: usize, value: T) {
+        let cols = self.cols;
+        let idx = row * cols + col;
+        self.data[idx] = value;
+
+        let data: &[T] = &self.data;
+        let combine = |a: &Op::Output, b: &Op::Output| Op::combine(data, a, b);
+
+        let mut path = leaf_path(&self.tree, 0, row);
+
+        // Repair the column trees bottom-up.
+        let leaf_idx = path.pop().unwrap();
+        update_tree(
+            &mut self.tree[leaf_idx].value,
+            col,
+            Op::lift(data, idx),
+            combine,
+        );
+
+        while let Some(node_idx) = path.pop() {
+            let node = &self.tree[node_idx];
+            let l_leaf = Self::leaf(&self.tree[node.left].value, cols, col);
+
+            let value = if node.right == usize::MAX {
+                l_leaf.clone()
+            } else {
+                combine(l_leaf, Self::leaf(&self.tree[node.right].value, cols, col))
+            };
+
+            update_tree(&mut self.tree[node_idx].value, col, value, combine);
+        }
+    }
+
+    /// Returns the value of the leaf of the given column in the given column
+    /// tree.
+    #[inline]
+    fn leaf(col_tree: &[Node<Op::Output>], cols: usize, col: usize) -> &Op::Output {
+        // The bottom layer is stored last.
+        &col_tree[col_tree.len() - cols + col].value
+    }
+}
+
+impl<T, Op: Selection<T>> SegmentTree2D<T, Op> {
+    /// Performs a query on the given rectangle [r1, r2] x [c1, c2].
+    /// Returns the row and column of the minimum in that rectangle.
+    /// Behaviour is undefined if the given rectangle is invalid or
+    /// pre-processing has not been done.
+    pub fn query(&self, r1: usize, r2: usize, c1: usize, c2: usize) -> (usize, usize) {
+        let idx = self.fold(r1, r2, c1, c2);
+        (idx / self.cols, idx % self.cols)
+    }
+}
@@ -0,0 +1,375 @@
+use std::{marker::PhantomData, rc::Rc};
+
+use super::{
+    is_min,
+    segment_tree::{build_tree, Node},
+    Natural, Order, Reverse, Rmq, RmqUpdate,
+};
+
+/// States which extreme of a range an update changes.
+#[derive(Clone, Copy)]
+enum Bound {
+    /// The update lowers all entries above a value (chmin).
+    Upper,
+
+    /// The update raises all entries below a value (chmax).
+    Lower,
+}
+
+/// The smallest or largest entries of a range.
+#[derive(Clone)]
+struct Extreme<T> {
+    /// The smallest (largest) value.
+    val: T,
+
+    /// The index of that value. If several entries have it, the order's
+    /// tie-breaking policy determines the index.
+    idx: usize,
+
+    /// The second smallest (largest) value. None if all entries are equal.
+    second: Option<T>,
+
+    /// The number of entries with the smallest (largest) value.
+    cnt: usize,
+}
+
+/// The value of a node of a SegmentTreeBeats.
+#[derive(Clone)]
+struct Entry<T> {
+    /// The minimum of the node's covered range.
+    min: Extreme<T>,
+
+    /// The maximum of the node's covered range.
+    max: Extreme<T>,
+
+    /// The sum of the node's covered range.
+    sum: i64,
+}
+
+/// Represents a RMQ that uses a "segment tree beats".
+/// Allows to replace all entries in a range by their minimum (or maximum)
+/// with a value and answers minimum, maximum, and sum queries.
+/// Updates are not stored as tags. Instead, a node's minimum and maximum bound
+/// the values of its children, which are repaired when visited.
+/// Unlike other trees, entries have to convert into i64: a clamp changes the
+/// sum of a node by (new - old) * count, which needs subtraction and a
+/// multiplication with the count of entries. Minima and maxima still only use
+/// the given order.
+/// Runtime: O(n) | O(log n), updates amortized O(log² n)
+pub struct SegmentTreeBeats<T, O: Order<T> = Natural> {
+    /// The original data. Not changed by updates.
+    data: Rc<[T]>,
+    tree: Vec<Node<Entry<T>>>,
+    phantom_data: PhantomData<O>,
+}
+
+impl<T: Clone + Into<i64>, O: Order<T>> SegmentTreeBeats<T, O> {
+    /// Constructor.
+    pub fn new(data: Rc<[T]>) -> Self {
+        Self {
+            data,
+            tree: Vec::new(),
+            phantom_data: PhantomData,
+        }
+    }
+
+    /// Pre-processes the data to allow queries.
+    pub fn process_data(&mut self) {
+        let data: &[T] = &self.data;
+
+        self.tree = build_tree(
+            data.len(),
+            |i| {
+                let ext = Extreme {
+                    val: data[i].clone(),
+                    idx: i,
+                    second: None,
+                    cnt: 1,
+                };
+
+                Entry {
+                    min: ext.clone(),
+                    max: ext,
+                    sum: data[i].clone().into(),
+                }
+            },
+            |a, b| Self::combine(a, b),
+        );
+    }
+
+    /// Performs a query on the given range.
+    /// Returns the index of the minimum in that range.
+    /// Behaviour is undefined if the given range is invalid or pre-processing
+    /// has not been done.
+    pub fn query_min(&self, i: usize, j: usize) -> usize {
+        self.fold(i, j).min.idx
+    }
+
+    /// Performs a query on the given range.
+    /// Returns the index of the maximum in that range.
+    /// Behaviour is undefined if the given range is invalid or pre-processing
+    /// has not been done.
+    pub fn query_max(&self, i: usize, j: usize) -> usize {
+        self.fold(i, j).max.idx
+    }
+
+    /// Returns the sum of all entries in the given range.
+    /// Behaviour is undefined if the given range is invalid or pre-processing
+    /// has not been done.
+    pub fn query_sum(&self, i: usize, j: usize) -> i64 {
+        self.fold(i, j).sum
+    }
+
+    /// Returns the current value of the entry at the given index.
+    /// Behaviour is undefined if pre-processing has not been done.
+    pub fn get(&self, idx: usize) -> T {
+        self.fold(idx, idx).min.val
+    }
+
+    /// Replaces each entry in the range [i, j] by the minimum of it and the
+    /// given value.
+    /// Behaviour is undefined if the given range is invalid or pre-processing
+    /// has not been done.
+    pub fn range_chmin(&mut self, i: usize, j: usize, value: T) {
+        self.update_node(0, i, j, &value, Bound::Upper);
+    }
+
+    /// Replaces each entry in the range [i, j] by the maximum of it and the
+    /// given value.
+    /// Behaviour is undefined if the given range is invalid or pre-processing
+    /// has not been done.
+    pub fn range_chmax(&mut self, i: usize, j: usize, value: T) {
+        self.update_node(0, i, j, &value, Bound::Lower);
+    }
+
+    /// Applies the given update to the entries of the given node that are in
+    /// the range [i, j].
+    fn update_node(&mut self, node_idx: usize, i: usize, j: usize, x: &T, bound: Bound) {
+        let node = &self.tree[node_idx];
+        let near = Self::near(&node.value, bound);
+
+        if j < node.fr_idx || node.to_idx < i || !Self::inside(bound, x, &near.val) {
+            // Disjoint ranges or no entry changes.
+            return;
+        }
+
+        let only_extremes = match &near.second {
+            Some(second) => Self::inside(bound, second, x),
+            None => true,
+        };
+
+        if i <= node.fr_idx && node.to_idx <= j && only_extremes {
+            // Node is covered completely and only its extremes change.
+            Self::clamp(&mut self.tree[node_idx].value, x, bound);
+            return;
+        }
+
+        // Repair the children first, since they are changed now.
+        let (left, right) = (node.left, node.right);
+        self.push_down(node_idx);
+
+        self.update_node(left, i, j, x, bound);
+        if right != usize::MAX {
+            self.update_node(right, i, j, x, bound);
+        }
+
+        // Repair node.
+        let value = if right == usize::MAX {
+            self.tree[left].value.clone()
+        } else {
+            Self::combine(&self.tree[left].value, &self.tree[right].value)
+        };
+        self.tree[node_idx].value = value;
+    }
+
+    /// Bounds the children of the given node by the node's minimum and
+    /// maximum.
+    fn push_down(&mut self, node_idx: usize) {
+        let node = &self.tree[node_idx];
+        let (left, right) = (node.left, node.right);
+        let parent = node.value.clone();
+
+        Self::bound_child(&parent, &mut self.tree[left].value);
+        if right != usize::MAX {
+            Self::bound_child(&parent, &mut self.tree[right].value);
+        }
+    }
+
+    /// Combines the entries of the given range.
+    fn fold(&self, i: usize, j: usize) -> Entry<T> {
+        self.fold_node(0, &self.tree[0].value, i, j).unwrap()
+    }
+
+    /// Combines the entries of the given node that are in the range [i, j].
+    /// Returns None if there are none.
+    /// The given entry is the node's value, bounded by its ancestors.
+    fn fold_node(&self, node_idx: usize, entry: &Entry<T>, i: usize, j: usize) -> Option<Entry<T>> {
+        let node = &self.tree[node_idx];
+
+        if j < node.fr_idx || node.to_idx < i {
+            // Disjoint ranges.
+            return None;
+        }
+
+        if i <= node.fr_idx && node.to_idx <= j {
+            // Node is covered completely.
+            return Some(entry.clone());
+        }
+
+        let mut l_entry = self.tree[node.left].value.clone();
+        Self::bound_child(entry, &mut l_entry);
+        let l_res = self.fold_node(node.left, &l_entry, i, j);
+
+        if node.right == usize::MAX {
+            return l_res;
+        }
+
+        let mut r_entry = self.tree[node.right].value.clone();
+        Self::bound_child(entry, &mut r_entry);
+        let r_res = self.fold_node(node.right, &r_entry, i, j);
+
+        match (l_res, r_res) {
+            (Some(l), Some(r)) => Some(Self::combine(&l, &r)),
+            (l, None) => l,
+            (None, r) => r,
+        }
+    }
+
+    /// Applies the updates a parent has received but its child has not.
+    #[inline]
+    fn bound_child(parent: &Entry<T>, child: &mut Entry<T>) {
+        for bound in [Bound::Upper, Bound::Lower] {
+            let p_val = &Self::near(parent, bound).val;
+            if Self::inside(bound, p_val, &Self::near(child, bound).val) {
+                Self::clamp(child, p_val, bound);
+            }
+        }
+    }
+
+    /// Moves the extremes of the given entry on the side of the bound to the
+    /// given value. Requires that no other entry is beyond that value.
+    fn clamp(entry: &mut Entry<T>, x: &T, bound: Bound) {
+        let (near, far) = match bound {
+            Bound::Upper => (&mut entry.max, &mut entry.min),
+            Bound::Lower => (&mut entry.min, &mut entry.max),
+        };
+
+        entry.sum += (x.clone().into() - near.val.clone().into()) * near.cnt as i64;
+
+        if Self::equal(&far.val, &near.val) {
+            // All entries are equal.
+            far.val = x.clone();
+        } else if far
+            .second
+            .as_ref()
+            .is_some_and(|s| Self::equal(s, &near.val))
+        {
+            // There are only two values.
+            far.second = Some(x.clone());
+        }
+
+        near.val = x.clone();
+    }
+
+    /// Returns the extremes of the given entry on the side of the bound.
+    #[inline]
+    fn near(entry: &Entry<T>, bound: Bound) -> &Extreme<T> {
+        match bound {
+            Bound::Upper => &entry.max,
+            Bound::Lower => &entry.min,
+        }
+    }
+
+    /// Determines if a is strictly closer to the middle than b. That is,
+    /// a < b for an upper bound and a > b for a lower bound.
+    #[inline]
+    fn inside(bound: Bound, a: &T, b: &T) -> bool {
+        match bound {
+            Bound::Upper => O::less(a, b),
+            Bound::Lower => O::less(b, a),
+        }
+    }
+
+    /// Determines if neither value is smaller than the other.
+    #[inline]
+    fn equal(a: &T, b: &T) -> bool {
+        !O::less(a, b) && !O::less(b, a)
+    }
+
+    /// Determines the value of a node from the values of its children.
+    #[inline]
+    fn combine(a: &Entry<T>, b: &Entry<T>) -> Entry<T> {
+        Entry {
+            min: Self::combine_extremes::<O>(&a.min, &b.min),
+            max: Self::combine_extremes::<Reverse<O>>(&a.max, &b.max),
+            sum: a.sum + b.sum,
+        }
+    }
+
+    /// Combines the smallest entries of two ranges with respect to the given
+    /// order.
+    fn combine_extremes<P: Order<T>>(a: &Extreme<T>, b: &Extreme<T>) -> Extreme<T> {
+        if !P::less(&a.val, &b.val) && !P::less(&b.val, &a.val) {
+            // Both have the same extreme value.
+            let second = match (&a.second, &b.second) {
+                (Some(s), Some(t)) => Some(if P::less(t, s) { t } else { s }.clone()),
+                (s, None) => s.clone(),
+                (None, t) => t.clone(),
+            };
+
+            let idx = if is_min::<T, P>(&a.val, a.idx, &b.val, b.idx) {
+                a.idx
+            } else {
+                b.idx
+            };
+
+            return Extreme {
+                val: a.val.clone(),
+                idx,
+                second,
+                cnt: a.cnt + b.cnt,
+            };
+        }
+
+        let (min, other) = if P::less(&a.val, &b.val) {
+            (a, b)
+        } else {
+            (b, a)
+        };
+
+        // The other range's extreme is a candidate for the second value.
+        let second = match &min.second {
+            Some(s) if P::less(s, &other.val) => s.clone(),
+            _ => other.val.clone(),
+        };
+
+        Extreme {
+            val: min.val.clone(),
+            idx: min.idx,
+            second: Some(second),
+            cnt: min.cnt,
+        }
+    }
+}
+
+impl<T: Clone + Into<i64>, O: Order<T>> Rmq<T> for SegmentTreeBeats<T, O> {
+    fn new(data: Rc<[T]>) -> Self {
+        Self::new(data)
+    }
+
+    fn process_data(&mut self) {
+        self.process_data();
+    }
+
+    fn query(&self, i: usize, j: usize) -> usize {
+        self.query_min(i, j)
+    }
+}
+
+impl<T: Clone + Into<i64>, O: Order<T>> RmqUpdate<T> for SegmentTreeBeats<T, O> {
+    fn update(&mut self, idx: usize, value: T) {
+        // Afterwards, the entry is neither larger nor smaller than the value.
+        self.range_chmin(idx, idx, value.clone());
+        self.range_chmax(idx, idx, value);
+    }
+}
@@ -0,0 +1,79 @@
+use std::{marker::PhantomData, rc::Rc};
+
+use super::{min_index, Natural, Order, Rmq};
+
+/// Represents a RMQ that uses a segment tree which is stored in an implicit
+/// layout to run queries.
+/// Nodes are stored in breadth-first order (Eytzinger layout): the children of
+/// the node at index k are at 2k and 2k + 1, and the leaf of data[i] is at
+/// n + i. Nodes therefore only store the index of their minimum, and queries
+/// walk up from the leaves through adjacent memory instead of following
+/// pointers.
+/// Runtime: O(n) | O(log n)
+pub struct SegmentTreeCache<T, O: Order<T> = Natural> {
+    data: Rc<[T]>,
+
+    /// The index of the minimum in each node's range. tree[0] is unused.
+    tree: Vec<usize>,
+
+    phantom_data: PhantomData<O>,
+}
+
+impl<T, O: Order<T>> Rmq<T> for SegmentTreeCache<T, O> {
+    fn new(data: Rc<[T]>) -> Self {
+        Self {
+            data,
+            tree: Vec::new(),
+            phantom_data: PhantomData,
+        }
+    }
+
+    fn process_data(&mut self) {
+        let n = self.data.len();
+
+        self.tree.resize(2 * n, 0);
+
+        // Build bottom layer.
+        for i in 0..n {
+            self.tree[n + i] = i;
+        }
+
+        // Build tree bottom-up.
+        for k in (1..n).rev() {
+            let l_min = self.tree[2 * k];
+            let r_min = self.tree[2 * k + 1];
+
+            self.tree[k] = min_index::<T, O>(&self.data, l_min, r_min);
+        }
+    }
+
+    fn query(&self, i: usize, j: usize) -> usize {
+        let n = self.data.len();
+
+        let mut min_idx = i;
+
+        // Half-open range [l, r) of leaves.
+        let mut l = i + n;
+        let mut r = j + n + 1;
+
+        while l < r {
+            // If l is a right child, its parent covers elements left of i.
+            // Hence, use l itself and continue with its right neighbour.
+            if l & 1 == 1 {
+                min_idx = min_index::<T, O>(&self.data, min_idx, self.tree[l]);
+                l += 1;
+            }
+
+            // Symmetric for r (exclusive).
+            if r & 1 == 1 {
+                r -= 1;
+                min_idx = min_index::<T, O>(&self.data, min_idx, self.tree[r]);
+            }
+
+            l >>= 1;
+            r >>= 1;
+        }
+
+        min_idx
+    }
+}
@@ -0,0 +1,255 @@
+//! Defines associative operations to combine the entries in a range.
+
+use std::{
+    marker::PhantomData,
+    ops::{self, Add, Div, Mul, Rem, Sub},
+};
+
+use super::{min_index, Natural, Order, Reverse};
+
+/// Represents an associative operation over the entries of some data.
+/// The entries of a range are first lifted into the output type and then
+/// combined from left to right. The data is passed along so that operations
+/// can refer to entries by their index.
+pub trait Semigroup<T> {
+    /// The type of a combined range.
+    type Output: Clone;
+
+    /// Returns the result for the range [idx, idx].
+    fn lift(data: &[T], idx: usize) -> Self::Output;
+
+    /// Combines the results of two adjacent ranges.
+    /// `a` belongs to the left range and `b` to the right one.
+    fn combine(data: &[T], a: &Self::Output, b: &Self::Output) -> Self::Output;
+}
+
+/// Marks operations for which combining a result with itself does not change
+/// it. Such operations allow to combine overlapping ranges.
+pub trait Idempotent<T>: Semigroup<T> {}
+
+/// Marks operations that select one of the entries of a range. Their result is
+/// the index of that entry.
+pub trait Selection<T>: Idempotent<T, Output = usize> {}
+
+/// The index of the minimum in a range with respect to the given order.
+pub struct Min<O = Natural>(PhantomData<O>);
+
+impl<T, O: Order<T>> Semigroup<T> for Min<O> {
+    type Output = usize;
+
+    fn lift(_: &[T], idx: usize) -> usize {
+        idx
+    }
+
+    fn combine(data: &[T], a: &usize, b: &usize) -> usize {
+        min_index::<T, O>(data, *a, *b)
+    }
+}
+
+impl<T, O: Order<T>> Idempotent<T> for Min<O> {}
+impl<T, O: Order<T>> Selection<T> for Min<O> {}
+
+/// The index of the maximum in a range with respect to the given order.
+pub type Max<O = Natural> = Min<Reverse<O>>;
+
+/// The indices of the two smallest entries in a range with respect to the
+/// given order. The second one is None if the range has a single entry.
+/// If several entries are equal, the order's tie-breaking policy determines
+/// which one comes first.
+pub struct MinTwo<O = Natural>(PhantomData<O>);
+
+impl<T, O: Order<T>> Semigroup<T> for MinTwo<O> {
+    type Output = (usize, Option<usize>);
+
+    fn lift(_: &[T], idx: usize) -> Self::Output {
+        (idx, None)
+    }
+
+    fn combine(data: &[T], a: &Self::Output, b: &Self::Output) -> Self::Output {
+        let first = min_index::<T, O>(data, a.0, b.0);
+
+        // The second one is the smallest of all other candidates. Ranges may
+        // overlap; hence, the first one may appear twice.
+        let second = [Some(a.0), a.1, Some(b.0), b.1]
+            .into_iter()
+            .flatten()
+            .filter(|&idx| idx != first)
+            .reduce(|x, y| min_index::<T, O>(data, x, y));
+
+        (first, second)
+    }
+}
+
+impl<T, O: Order<T>> Idempotent<T> for MinTwo<O> {}
+
+/// Determines the minimum of a range while ignoring the entry at the given
+/// index, using the result of MinTwo for that range. Returns None if there is
+/// no other entry.
+#[inline]
+pub(super) fn min_excluding(min_two: (usize, Option<usize>), idx: usize) -> Option<usize> {
+    match min_two {
+        (first, second) if first == idx => second,
+        (first, _) => Some(first),
+    }
+}
+
+/// The sum of all entries in a range.
+pub struct Sum;
+
+impl<T: Clone + Add<Output = T>> Semigroup<T> for Sum {
+    type Output = T;
+
+    fn lift(data: &[T], idx: usize) -> T {
+        data[idx].clone()
+    }
+
+    fn combine(_: &[T], a: &T, b: &T) -> T {
+        a.clone() + b.clone()
+    }
+}
+
+/// The product of all entries in a range.
+/// The left range is the left factor.
+pub struct Product;
+
+impl<T: Clone + Mul<Output = T>> Semigroup<T> for Product {
+    type Output = T;
+
+    fn lift(data: &[T], idx: usize) -> T {
+        data[idx].clone()
+    }
+
+    fn combine(_: &[T], a: &T, b: &T) -> T {
+        a.clone() * b.clone()
+    }
+}
+
+/// The greatest common divisor of all entries in a range.
+/// The result is never negative. Intended for integers; `T::default()` has to
+/// be 0.
+pub struct Gcd;
+
+impl<T> Semigroup<T> for Gcd
+where
+    T: Clone + PartialOrd + Default + Rem<Output = T> + Sub<Output = T>,
+{
+    type Output = T;
+
+    fn lift(data: &[T], idx: usize) -> T {
+        abs(data[idx].clone())
+    }
+
+    fn combine(_: &[T], a: &T, b: &T) -> T {
+        gcd(a.clone(), b.clone())
+    }
+}
+
+impl<T> Idempotent<T> for Gcd where
+    T: Clone + PartialOrd + Default + Rem<Output = T> + Sub<Output = T>
+{
+}
+
+/// The least common multiple of all entries in a range.
+/// The result is never negative. Intended for integers; `T::default()` has to
+/// be 0.
+pub struct Lcm;
+
+impl<T> Semigroup<T> for Lcm
+where
+    T: Clone
+        + PartialOrd
+        + Default
+        + Rem<Output = T>
+        + Div<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>,
+{
+    type Output = T;
+
+    fn lift(data: &[T], idx: usize) -> T {
+        abs(data[idx].clone())
+    }
+
+    fn combine(_: &[T], a: &T, b: &T) -> T {
+        let zero = T::default();
+        if *a == zero || *b == zero {
+            return zero;
+        }
+
+        // lcm(a, b) = a / gcd(a, b) * b
+        a.clone() / gcd(a.clone(), b.clone()) * b.clone()
+    }
+}
+
+impl<T> Idempotent<T> for Lcm where
+    T: Clone
+        + PartialOrd
+        + Default
+        + Rem<Output = T>
+        + Div<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>
+{
+}
+
+/// The bitwise and of all entries in a range.
+pub struct BitAnd;
+
+impl<T: Clone + ops::BitAnd<Output = T>> Semigroup<T> for BitAnd {
+    type Output = T;
+
+    fn lift(data: &[T], idx: usize) -> T {
+        data[idx].clone()
+    }
+
+    fn combine(_: &[T], a: &T, b: &T) -> T {
+        a.clone() & b.clone()
+    }
+}
+
+impl<T: Clone + ops::BitAnd<Output = T>> Idempotent<T> for BitAnd {}
+
+/// The bitwise or of all entries in a range.
+pub struct BitOr;
+
+impl<T: Clone + ops::BitOr<Output = T>> Semigroup<T> for BitOr {
+    type Output = T;
+
+    fn lift(data: &[T], idx: usize) -> T {
+        data[idx].clone()
+    }
+
+    fn combine(_: &[T], a: &T, b: &T) -> T {
+        a.clone() | b.clone()
+    }
+}
+
+impl<T: Clone + ops::BitOr<Output = T>> Idempotent<T> for BitOr {}
+
+/// Computes the greatest common divisor of the given numbers using the
+/// Euclidean algorithm. Both numbers have to be non-negative.
+fn gcd<T>(mut a: T, mut b: T) -> T
+where
+    T: Clone + PartialEq + Default + Rem<Output = T>,
+{
+    let zero = T::default();
+
+    while b != zero {
+        let r = a % b.clone();
+        a = b;
+        b = r;
+    }
+
+    a
+}
+
+/// Returns the absolute value of the given number.
+#[inline]
+fn abs<T: PartialOrd + Default + Sub<Output = T>>(a: T) -> T {
+    let zero = T::default();
+    if a < zero {
+        zero - a
+    } else {
+        a
+    }
+}
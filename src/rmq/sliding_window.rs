@@ -0,0 +1,162 @@
+use std::{collections::VecDeque, marker::PhantomData};
+
+use super::{is_min, Natural, Order};
+
+/// Represents a window over a stream of values that is extended at the back
+/// and shrunk at the front. Values are identified by their position in the
+/// stream, that is, the number of values pushed before them.
+///
+/// Keeps a monotonic deque: the minimum of the window, the minimum of the
+/// values after it, and so on. Values which cannot become the minimum of the
+/// window anymore are removed.
+/// Runtime: O(1) amortized per operation
+pub struct SlidingWindow<T, O: Order<T> = Natural> {
+    /// The candidates for the minimum and their positions in the stream.
+    deque: VecDeque<(usize, T)>,
+
+    /// The position of the window's first value.
+    front: usize,
+
+    /// The number of values pushed so far.
+    end: usize,
+
+    phantom_data: PhantomData<O>,
+}
+
+impl<T, O: Order<T>> SlidingWindow<T, O> {
+    /// Constructor. Creates an empty window.
+    pub fn new() -> Self {
+        Self {
+            deque: VecDeque::new(),
+            front: 0,
+            end: 0,
+            phantom_data: PhantomData,
+        }
+    }
+
+    /// Returns the number of values in the window.
+    pub fn len(&self) -> usize {
+        self.end - self.front
+    }
+
+    /// Determines if the window is empty.
+    pub fn is_empty(&self) -> bool {
+        self.front == self.end
+    }
+
+    /// Returns the position of the window's first value in the stream.
+    pub fn front(&self) -> usize {
+        self.front
+    }
+
+    /// Adds the given value to the end of the window.
+    /// Returns its position in the stream.
+    pub fn push_back(&mut self, value: T) -> usize {
+        let idx = self.end;
+
+        // Remove all values that are not preferred over the new one as
+        // minimum. They cannot be the minimum of the window anymore.
+        while let Some((b_idx, b_val)) = self.deque.back() {
+            if is_min::<T, O>(b_val, *b_idx, &value, idx) {
+                break;
+            }
+
+            self.deque.pop_back();
+        }
+
+        self.deque.push_back((idx, value));
+        self.end += 1;
+
+        idx
+    }
+
+    /// Removes the first value from the window.
+    /// Returns its position in the stream, or None if the window is empty. In
+    /// that case, the window is not changed.
+    pub fn pop_front(&mut self) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+
+        if self
+            .deque
+            .front()
+            .is_some_and(|(idx, _)| *idx == self.front)
+        {
+            self.deque.pop_front();
+        }
+
+        self.front += 1;
+
+        Some(self.front - 1)
+    }
+
+    /// Returns the minimum of the window and its position in the stream. If
+    /// there are several, the order's tie-breaking policy determines which
+    /// one. Returns None if the window is empty.
+    pub fn current_min(&self) -> Option<(usize, &T)> {
+        self.deque.front().map(|(idx, val)| (*idx, val))
+    }
+}
+
+impl<T, O: Order<T>> Default for SlidingWindow<T, O> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An iterator over the minima of all windows of a fixed size of a sequence.
+/// See `window_minima()`.
+pub struct WindowMinima<I: Iterator, O: Order<I::Item> = Natural> {
+    iter: I,
+    size: usize,
+    window: SlidingWindow<I::Item, O>,
+}
+
+impl<I: Iterator, O: Order<I::Item>> Iterator for WindowMinima<I, O> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        // Remove the first value of the previous window.
+        if self.window.len() == self.size {
+            self.window.pop_front();
+        }
+
+        while self.window.len() < self.size {
+            self.window.push_back(self.iter.next()?);
+        }
+
+        self.window.current_min().map(|(idx, _)| idx)
+    }
+}
+
+/// Returns an iterator that yields, for each window of the given size of the
+/// given sequence, the index of the minimum in that window (from left to
+/// right). That is, for a sequence of length n, it yields the results for
+/// [0, size - 1], [1, size], ..., [n - size, n - 1].
+/// Behaviour is undefined if the size is 0.
+/// Runtime: O(n)
+pub fn window_minima<I>(values: I, size: usize) -> WindowMinima<I::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: PartialOrd,
+{
+    window_minima_by::<I, Natural>(values, size)
+}
+
+/// Returns an iterator over the minima of all windows of the given size with
+/// respect to the given order.
+/// See `window_minima()`.
+pub fn window_minima_by<I, O>(values: I, size: usize) -> WindowMinima<I::IntoIter, O>
+where
+    I: IntoIterator,
+    O: Order<I::Item>,
+{
+    debug_assert!(size > 0);
+
+    WindowMinima {
+        iter: values.into_iter(),
+        size,
+        window: SlidingWindow::new(),
+    }
+}
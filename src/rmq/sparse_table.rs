@@ -1,27 +1,34 @@
-use std::rc::Rc;
+use std::{marker::PhantomData, rc::Rc};
 
 use crate::log::log_f;
 
-use super::{min_index, Rmq};
+use super::{semigroup::min_excluding, Idempotent, Min, MinTwo, Order, Rmq, Selection};
 
-// ToDo: Description
+/// Represents a table which stores the result for every range whose length is
+/// a power of two. Any range is then covered by two (overlapping) such ranges.
+/// Hence, the operation has to be idempotent; by default, it is the minimum.
 /// Runtime: O(n log n) | O(1)
-pub struct SparseTable<T> {
+pub struct SparseTable<T, Op: Idempotent<T> = Min> {
     data: Rc<[T]>,
 
-    /// Table with minimums in various ranges.
-    table: Vec<Vec<usize>>,
+    /// Table with results in various ranges.
+    table: Vec<Vec<Op::Output>>,
+
+    phantom_data: PhantomData<Op>,
 }
 
-impl<T: PartialOrd> Rmq<T> for SparseTable<T> {
-    fn new(data: Rc<[T]>) -> Self {
+impl<T, Op: Idempotent<T>> SparseTable<T, Op> {
+    /// Constructor.
+    pub fn new(data: Rc<[T]>) -> Self {
         Self {
             data,
             table: Vec::new(),
+            phantom_data: PhantomData,
         }
     }
 
-    fn process_data(&mut self) {
+    /// Pre-processes the data to allow queries.
+    pub fn process_data(&mut self) {
         let data: &[T] = &self.data;
         let n = data.len();
 
@@ -32,39 +39,70 @@ impl<T: PartialOrd> Rmq<T> for SparseTable<T> {
         // length as second. That way, we always use the same vector instead
         // of two different ones; thereby improving caching and improving the
         // runtime slightly.
-        self.table.resize_with(table_height, || vec![0; n]);
+        self.table.clear();
+        self.table.reserve(table_height);
 
-        for i in 0..n {
-            self.table[0][i] = i;
-        }
+        self.table.push((0..n).map(|i| Op::lift(data, i)).collect());
 
         for j in 1..table_height {
+            let mut row = Vec::with_capacity(n);
+
             for i in 0..n {
-                // Compare the two ranges below:
+                // Combine the two ranges below:
                 // M[j - 1, i] and M[j - 1, i + 2^{j - 1} - 1]
 
                 // Check that right index is not out of range.
                 let l_idx = i;
                 let r_idx = std::cmp::min(n - 1, i + (1 << (j - 1)));
 
-                let l_min = self.table[j - 1][l_idx];
-                let r_min = self.table[j - 1][r_idx];
+                let l_res = &self.table[j - 1][l_idx];
+                let r_res = &self.table[j - 1][r_idx];
 
-                self.table[j][i] = min_index(&self.data, l_min, r_min);
+                row.push(Op::combine(data, l_res, r_res));
             }
+
+            self.table.push(row);
         }
     }
 
-    fn query(&self, i: usize, j: usize) -> usize {
+    /// Combines all entries in the given range [i, j].
+    /// Behaviour is undefined if the given range is invalid or pre-processing
+    /// has not been done.
+    pub fn fold(&self, i: usize, j: usize) -> Op::Output {
         // k = floor(log (j − i))
         let k = log_f(j - i);
 
         // M[k, i]
         // M[k, j − 2^k + 1]
 
-        let min_1 = self.table[k][i];
-        let min_2 = self.table[k][j - (1 << k) + 1];
+        let res_1 = &self.table[k][i];
+        let res_2 = &self.table[k][j - (1 << k) + 1];
+
+        Op::combine(&self.data, res_1, res_2)
+    }
+}
+
+impl<T, O: Order<T>> SparseTable<T, MinTwo<O>> {
+    /// Performs a query on the given range while ignoring the entry at the
+    /// given index. Returns the index of the minimum of the remaining entries,
+    /// or None if there are none.
+    /// Behaviour is undefined if the given range is invalid or pre-processing
+    /// has not been done.
+    pub fn query_excluding(&self, i: usize, j: usize, idx: usize) -> Option<usize> {
+        min_excluding(self.fold(i, j), idx)
+    }
+}
+
+impl<T, Op: Selection<T>> Rmq<T> for SparseTable<T, Op> {
+    fn new(data: Rc<[T]>) -> Self {
+        Self::new(data)
+    }
+
+    fn process_data(&mut self) {
+        self.process_data();
+    }
 
-        min_index(&self.data, min_1, min_2)
+    fn query(&self, i: usize, j: usize) -> usize {
+        self.fold(i, j)
     }
 }
@@ -0,0 +1,144 @@
+use std::{marker::PhantomData, rc::Rc};
+
+use crate::log::log_f;
+
+use super::{Idempotent, Min, Selection};
+
+/// Represents a table which stores the result for every rectangle whose height
+/// and width are powers of two. Any rectangle is then covered by four
+/// (overlapping) such rectangles. Hence, the operation has to be idempotent;
+/// by default, it is the minimum.
+/// The data is a matrix stored row by row. Operations refer to entries by
+/// their index in that order.
+/// Runtime: O(nm log n log m) | O(1)
+pub struct SparseTable2D<T, Op: Idempotent<T> = Min> {
+    data: Rc<[T]>,
+
+    /// The number of columns of the matrix.
+    cols: usize,
+
+    /// Table with results in various rectangles. table[kr][kc][r * cols + c]
+    /// is the result for [r, r + 2^kr - 1] x [c, c + 2^kc - 1].
+    table: Vec<Vec<Vec<Op::Output>>>,
+
+    phantom_data: PhantomData<Op>,
+}
+
+impl<T, Op: Idempotent<T>> SparseTable2D<T, Op> {
+    /// Constructor. The length of the data has to be a multiple of the number
+    /// of columns.
+    /// Panics if there are no columns or the length is not such a multiple.
+    pub fn new(data: Rc<[T]>, cols: usize) -> Self {
+        assert!(
+            cols > 0 && data.len().is_multiple_of(cols),
+            "data of length {} cannot be split into rows of {} columns",
+            data.len(),
+            cols
+        );
+
+        Self {
+            data,
+            cols,
+            table: Vec::new(),
+            phantom_data: PhantomData,
+        }
+    }
+
+    /// Pre-processes the data to allow queries.
+    pub fn process_data(&mut self) {
+        let data: &[T] = &self.data;
+        let cols = self.cols;
+        let rows = data.len() / cols;
+
+        let r_height = log_f(rows) + 1;
+        let c_height = log_f(cols) + 1;
+
+        self.table.clear();
+        self.table.reserve(r_height);
+
+        // --- Rectangles of height 1, i.e., sparse tables of single rows. ---
+
+        let mut layer = Vec::with_capacity(c_height);
+        layer.push(
+            (0..data.len())
+                .map(|i| Op::lift(data, i))
+                .collect::<Vec<_>>(),
+        );
+
+        for kc in 1..c_height {
+            let lower: &Vec<Op::Output> = &layer[kc - 1];
+            let mut row = Vec::with_capacity(data.len());
+
+            for idx in 0..data.len() {
+                // Combine M[kc - 1, c] and M[kc - 1, c + 2^{kc - 1}].
+                // Check that right index is not out of range.
+                let (r, c) = (idx / cols, idx % cols);
+                let r_c = std::cmp::min(cols - 1, c + (1 << (kc - 1)));
+
+                row.push(Op::combine(data, &lower[idx], &lower[r * cols + r_c]));
+            }
+
+            layer.push(row);
+        }
+
+        self.table.push(layer);
+
+        // --- Combine rectangles vertically. ---
+
+        for kr in 1..r_height {
+            let mut layer = Vec::with_capacity(c_height);
+
+            for kc in 0..c_height {
+                let lower = &self.table[kr - 1][kc];
+                let mut row = Vec::with_capacity(data.len());
+
+                for idx in 0..data.len() {
+                    // Combine M[kr - 1, r] and M[kr - 1, r + 2^{kr - 1}].
+                    // Check that lower index is not out of range.
+                    let (r, c) = (idx / cols, idx % cols);
+                    let l_r = std::cmp::min(rows - 1, r + (1 << (kr - 1)));
+
+                    row.push(Op::combine(data, &lower[idx], &lower[l_r * cols + c]));
+                }
+
+                layer.push(row);
+            }
+
+            self.table.push(layer);
+        }
+    }
+
+    /// Combines all entries in the given rectangle [r1, r2] x [c1, c2].
+    /// Behaviour is undefined if the given rectangle is invalid or
+    /// pre-processing has not been done.
+    pub fn fold(&self, r1: usize, r2: usize, c1: usize, c2: usize) -> Op::Output {
+        let data: &[T] = &self.data;
+        let cols = self.cols;
+
+        let kr = log_f(r2 - r1);
+        let kc = log_f(c2 - c1);
+
+        let table = &self.table[kr][kc];
+
+        // The top and bottom rows as well as left and right columns of the
+        // four rectangles.
+        let (t_r, b_r) = (r1, r2 + 1 - (1 << kr));
+        let (l_c, r_c) = (c1, c2 + 1 - (1 << kc));
+
+        let top = Op::combine(data, &table[t_r * cols + l_c], &table[t_r * cols + r_c]);
+        let bot = Op::combine(data, &table[b_r * cols + l_c], &table[b_r * cols + r_c]);
+
+        Op::combine(data, &top, &bot)
+    }
+}
+
+impl<T, Op: Selection<T>> SparseTable2D<T, Op> {
+    /// Performs a query on the given rectangle [r1, r2] x [c1, c2].
+    /// Returns the row and column of the minimum in that rectangle.
+    /// Behaviour is undefined if the given rectangle is invalid or
+    /// pre-processing has not been done.
+    pub fn query(&self, r1: usize, r2: usize, c1: usize, c2: usize) -> (usize, usize) {
+        let idx = self.fold(r1, r2, c1, c2);
+        (idx / self.cols, idx % self.cols)
+    }
+}
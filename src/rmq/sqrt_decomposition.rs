@@ -0,0 +1,101 @@
+use std::{marker::PhantomData, rc::Rc};
+
+use super::{min_index, Natural, Order, Rmq, RmqUpdate};
+
+/// Represents a RMQ that splits the data into blocks of size sqrt(n) and
+/// stores the minimum of each block.
+/// Updates of an entry only need to repair the block containing it.
+/// Runtime: O(n) | O(sqrt n)
+pub struct SqrtDecomposition<T, O: Order<T> = Natural> {
+    /// A copy of the data, which is changed by updates.
+    data: Vec<T>,
+
+    /// States how large a single block of the data is.
+    block_size: usize,
+
+    /// The index of each block's minimum in the data.
+    block_min_idx: Vec<usize>,
+
+    phantom_data: PhantomData<O>,
+}
+
+impl<T: Clone, O: Order<T>> Rmq<T> for SqrtDecomposition<T, O> {
+    fn new(data: Rc<[T]>) -> Self {
+        Self {
+            data: data.to_vec(),
+            block_size: 0,
+            block_min_idx: Vec::new(),
+            phantom_data: PhantomData,
+        }
+    }
+
+    fn process_data(&mut self) {
+        let n = self.data.len();
+
+        self.block_size = std::cmp::max(1, n.isqrt());
+
+        // ceil(x / y) = floor((x - 1) / y) + 1
+        let block_count = (n - 1) / self.block_size + 1;
+
+        self.block_min_idx.clear();
+        self.block_min_idx.reserve(block_count);
+
+        for b in 0..block_count {
+            let b_sta = b * self.block_size;
+            let b_end = std::cmp::min(b_sta + self.block_size, n);
+
+            self.block_min_idx.push(self.scan(b_sta, b_end - 1));
+        }
+    }
+
+    fn query(&self, i: usize, j: usize) -> usize {
+        // Determine block indices.
+        let i_b = i / self.block_size;
+        let j_b = j / self.block_size;
+
+        if i_b == j_b {
+            // i and j are in the same block.
+            return self.scan(i, j);
+        }
+
+        // i and j are in different blocks.
+
+        let i_min = self.scan(i, (i_b + 1) * self.block_size - 1);
+        let j_min = self.scan(j_b * self.block_size, j);
+        let mut min_idx = min_index::<T, O>(&self.data, i_min, j_min);
+
+        // Determine the minimum in the blocks between i and j.
+        for b in (i_b + 1)..j_b {
+            min_idx = min_index::<T, O>(&self.data, min_idx, self.block_min_idx[b]);
+        }
+
+        min_idx
+    }
+}
+
+impl<T: Clone, O: Order<T>> RmqUpdate<T> for SqrtDecomposition<T, O> {
+    fn update(&mut self, idx: usize, value: T) {
+        self.data[idx] = value;
+
+        // Repair the block containing idx.
+        let b = idx / self.block_size;
+        let b_sta = b * self.block_size;
+        let b_end = std::cmp::min(b_sta + self.block_size, self.data.len());
+
+        self.block_min_idx[b] = self.scan(b_sta, b_end - 1);
+    }
+}
+
+impl<T, O: Order<T>> SqrtDecomposition<T, O> {
+    /// Determines the minimum in the given range by iterating over it.
+    fn scan(&self, i: usize, j: usize) -> usize {
+        // First entry is default minimum.
+        let mut min_idx = i;
+
+        for idx in (i + 1)..=j {
+            min_idx = min_index::<T, O>(&self.data, min_idx, idx);
+        }
+
+        min_idx
+    }
+}
@@ -0,0 +1,83 @@
+use super::{Natural, Order, Rmq};
+
+/// Determines the first index in the range [i, j] whose entry is smaller than
+/// x, using the given RMQ over the given data. Returns None if there is none.
+///
+/// The minimum of the prefix [i, k] is smaller than x for all k from that
+/// index on. Hence, a binary search over k finds it. Structures such as
+/// `SegmentTree` can do better.
+/// Behaviour is undefined if the given range is invalid or the RMQ uses a
+/// different order.
+/// Runtime: O(log n) queries
+pub fn find_first_below<T, R>(rmq: &R, data: &[T], i: usize, j: usize, x: &T) -> Option<usize>
+where
+    T: PartialOrd,
+    R: Rmq<T>,
+{
+    find_first_below_by::<T, Natural, R>(rmq, data, i, j, x)
+}
+
+/// Determines the first index in the range [i, j] whose entry is smaller than
+/// x with respect to the given order.
+/// See `find_first_below()`.
+pub fn find_first_below_by<T, O, R>(rmq: &R, data: &[T], i: usize, j: usize, x: &T) -> Option<usize>
+where
+    O: Order<T>,
+    R: Rmq<T>,
+{
+    if !O::less(&data[rmq.query(i, j)], x) {
+        return None;
+    }
+
+    // The prefix [i, hi] contains an entry smaller than x, [i, lo - 1] not.
+    let (mut lo, mut hi) = (i, j);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+
+        if O::less(&data[rmq.query(i, mid)], x) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+
+    Some(lo)
+}
+
+/// Determines the last index in the range [i, j] whose entry is smaller than
+/// x, using the given RMQ over the given data. Returns None if there is none.
+/// See `find_first_below()`.
+pub fn find_last_below<T, R>(rmq: &R, data: &[T], i: usize, j: usize, x: &T) -> Option<usize>
+where
+    T: PartialOrd,
+    R: Rmq<T>,
+{
+    find_last_below_by::<T, Natural, R>(rmq, data, i, j, x)
+}
+
+/// Determines the last index in the range [i, j] whose entry is smaller than
+/// x with respect to the given order.
+/// See `find_first_below()`.
+pub fn find_last_below_by<T, O, R>(rmq: &R, data: &[T], i: usize, j: usize, x: &T) -> Option<usize>
+where
+    O: Order<T>,
+    R: Rmq<T>,
+{
+    if !O::less(&data[rmq.query(i, j)], x) {
+        return None;
+    }
+
+    // The suffix [lo, j] contains an entry smaller than x, [hi + 1, j] not.
+    let (mut lo, mut hi) = (i, j);
+    while lo < hi {
+        let mid = hi - (hi - lo) / 2;
+
+        if O::less(&data[rmq.query(mid, j)], x) {
+            lo = mid;
+        } else {
+            hi = mid - 1;
+        }
+    }
+
+    Some(lo)
+}
@@ -0,0 +1,108 @@
+use std::{cmp::Ordering, collections::BinaryHeap, marker::PhantomData};
+
+use super::{is_min, Natural, Order, Rmq};
+
+/// Represents a range [l, r] whose minimum has not been reported yet.
+struct Candidate<'a, T, O> {
+    /// The value of the range's minimum.
+    value: &'a T,
+
+    /// The index of the range's minimum.
+    idx: usize,
+
+    l: usize,
+    r: usize,
+
+    phantom_data: PhantomData<O>,
+}
+
+impl<T, O: Order<T>> Ord for Candidate<'_, T, O> {
+    /// A candidate is larger than another if its minimum is preferred. That
+    /// way, the max-heap reports the smallest one first.
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.idx == other.idx {
+            Ordering::Equal
+        } else if is_min::<T, O>(self.value, self.idx, other.value, other.idx) {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        }
+    }
+}
+
+impl<T, O: Order<T>> PartialOrd for Candidate<'_, T, O> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, O: Order<T>> PartialEq for Candidate<'_, T, O> {
+    fn eq(&self, other: &Self) -> bool {
+        self.idx == other.idx
+    }
+}
+
+impl<T, O: Order<T>> Eq for Candidate<'_, T, O> {}
+
+/// Determines the indices of the k smallest entries in the range [i, j] using
+/// the given RMQ over the given data. The indices are sorted by their values;
+/// equal values are sorted by the tie-breaking policy (the right-most one
+/// first by default). If the range has fewer than k entries, all of them are
+/// returned.
+///
+/// The minimum of [i, j] is the smallest entry. The next one is the minimum of
+/// the range left or right of it, and so on. The ranges not reported yet are
+/// kept in a heap ordered by their minimum.
+/// Behaviour is undefined if the given range is invalid or the RMQ uses a
+/// different order.
+/// Runtime: O(k log k) and 2k + 1 queries
+pub fn top_k<T, R>(rmq: &R, data: &[T], i: usize, j: usize, k: usize) -> Vec<usize>
+where
+    T: PartialOrd,
+    R: Rmq<T>,
+{
+    top_k_by::<T, Natural, R>(rmq, data, i, j, k)
+}
+
+/// Determines the indices of the k smallest entries in the range [i, j] with
+/// respect to the given order.
+/// See `top_k()`.
+pub fn top_k_by<T, O, R>(rmq: &R, data: &[T], i: usize, j: usize, k: usize) -> Vec<usize>
+where
+    O: Order<T>,
+    R: Rmq<T>,
+{
+    let k = std::cmp::min(k, j - i + 1);
+    let mut result = Vec::with_capacity(k);
+
+    let candidate = |l: usize, r: usize| {
+        let idx = rmq.query(l, r);
+
+        Candidate::<T, O> {
+            value: &data[idx],
+            idx,
+            l,
+            r,
+            phantom_data: PhantomData,
+        }
+    };
+
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    heap.push(candidate(i, j));
+
+    while result.len() < k {
+        // There are always k - |result| entries left in the heap's ranges.
+        let min = heap.pop().unwrap();
+        result.push(min.idx);
+
+        // Split the range at its minimum.
+        if min.l < min.idx {
+            heap.push(candidate(min.l, min.idx - 1));
+        }
+        if min.idx < min.r {
+            heap.push(candidate(min.idx + 1, min.r));
+        }
+    }
+
+    result
+}
@@ -1,13 +1,19 @@
 //! Defines functions to compare the correctness and runtime of RMQ algorithms.
 
-use std::{mem::swap, rc::Rc, time::Instant};
+use std::{cmp::Reverse, collections::HashSet, mem::swap, rc::Rc, time::Instant};
 
 use rand::{rngs::StdRng, Rng, SeedableRng};
 
-use crate::{
+use rmq_rs::{
     log::log_f,
-    rmq::{PlusMinus, Rmq},
-    tree::Tree,
+    rmq::{
+        self, all_min_positions, cartesian_tree, circular_query, find_first_below, find_last_below,
+        offline_rmq, offline_rmq_by, top_k, window_minima, AppendOnly, DisjointSparseTable,
+        DocumentListing, Gcd, Idempotent, ImplicitTreap, Key, LazySegmentTree, Lcm, MinTwo,
+        Natural, Order, PersistentSegmentTree, PlusMinus, Rmq, RmqUpdate, SegmentTree,
+        SegmentTree2D, SegmentTreeBeats, Semigroup, SlidingWindow, SparseTable, SparseTable2D,
+    },
+    tree::{Lca, Tree},
 };
 
 /// The numeric type for testing.
@@ -17,15 +23,763 @@ pub type Number = i32;
 pub type TimePair = (i64, i64);
 
 /// Verifies that two RMQ algorithm create the same result.
-/// Randomly picks index pairs and compares the result.
+/// Randomly picks index pairs and compares the resulting indices.
 pub fn verify_algorithms<S, T>(data_size: usize, queries: usize, seed: u64) -> bool
 where
-    S: Rmq<Number>,
+    S: Rmq<Number>,
+    T: Rmq<Number>,
+{
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_data(data_size, &mut rng);
+
+    verify_algorithms_inner::<S, T, _>(data, rng, data_size, queries)
+}
+
+/// Verifies that two RMQ algorithm create the same result on data with many
+/// equal entries. That is, it verifies that both use the same tie-breaking
+/// policy.
+/// Randomly picks index pairs and compares the resulting indices.
+pub fn verify_ties<S, T>(data_size: usize, queries: usize, seed: u64) -> bool
+where
+    S: Rmq<Number>,
+    T: Rmq<Number>,
+{
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_duplicates(data_size, &mut rng);
+
+    verify_algorithms_inner::<S, T, _>(data, rng, data_size, queries)
+}
+
+/// Verifies that an RMQ algorithm creates correct results after updates.
+/// Randomly changes entries, picks index pairs after each change, and compares
+/// the result with the minimum determined by iterating over the range. Assumes
+/// that the algorithm uses the default order.
+pub fn verify_updates<T>(data_size: usize, queries: usize, seed: u64) -> bool
+where
+    T: RmqUpdate<Number>,
+{
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_data(data_size, &mut rng);
+
+    // Generate and test algorithm.
+    let mut rmq = T::new(data.clone());
+    rmq.process_data();
+
+    let mut values = data.to_vec();
+
+    // Verify algorithm.
+    for _ in 0..queries {
+        let idx = rng.gen_range(0..data_size);
+        let val = random_number(data_size, &mut rng);
+
+        values[idx] = val;
+        rmq.update(idx, val);
+
+        let (i, j) = random_index_pair(&mut rng, data_size);
+
+        // The default order reports the right-most minimum.
+        let expected = rightmost_min::<_, Natural>(&values, i, j);
+
+        if rmq.query(i, j) != expected {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Verifies that a lazy segment tree creates correct results after range
+/// updates.
+/// Randomly adds values to or assigns values to ranges, picks index pairs after
+/// each change, and compares the result with the minimum determined by
+/// iterating over the range.
+pub fn verify_range_updates(data_size: usize, queries: usize, seed: u64) -> bool {
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_data(data_size, &mut rng);
+
+    // Generate and test algorithm.
+    let mut tree = LazySegmentTree::<Number>::new(data.clone());
+    tree.process_data();
+
+    let mut values = data.to_vec();
+
+    // Small summands keep the values in range and create ties.
+    let max_add = log_f(data_size) as Number;
+
+    // Verify algorithm.
+    for _ in 0..queries {
+        let (i, j) = random_index_pair(&mut rng, data_size);
+
+        if rng.gen_bool(0.5) {
+            let val = rng.gen_range(-max_add..=max_add);
+            values[i..=j].iter_mut().for_each(|v| *v += val);
+            tree.add(i, j, val);
+        } else {
+            let val = random_number(data_size, &mut rng);
+            values[i..=j].fill(val);
+            tree.assign(i, j, val);
+        }
+
+        let (i, j) = random_index_pair(&mut rng, data_size);
+
+        // The default order reports the right-most minimum.
+        let expected = rightmost_min::<_, Natural>(&values, i, j);
+
+        if tree.query(i, j) != expected || tree.get(i) != values[i] {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Verifies that a segment tree beats creates correct results after clamping
+/// ranges.
+/// Randomly lowers or raises the entries of ranges to values, picks index
+/// pairs after each change, and compares the minimum, maximum, and sum with
+/// those determined by iterating over the range.
+pub fn verify_range_clamps(data_size: usize, queries: usize, seed: u64) -> bool {
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_data(data_size, &mut rng);
+
+    // Generate and test algorithm.
+    let mut tree = SegmentTreeBeats::<Number>::new(data.clone());
+    tree.process_data();
+
+    let mut values = data.to_vec();
+
+    // Verify algorithm.
+    for _ in 0..queries {
+        let (i, j) = random_index_pair(&mut rng, data_size);
+        let val = random_number(data_size, &mut rng);
+
+        if rng.gen_bool(0.5) {
+            values[i..=j].iter_mut().for_each(|v| *v = (*v).min(val));
+            tree.range_chmin(i, j, val);
+        } else {
+            values[i..=j].iter_mut().for_each(|v| *v = (*v).max(val));
+            tree.range_chmax(i, j, val);
+        }
+
+        let (i, j) = random_index_pair(&mut rng, data_size);
+
+        // The default order reports the right-most minimum and maximum.
+        let exp_min = rightmost_min::<_, Natural>(&values, i, j);
+        let exp_max = rightmost_min::<_, rmq::Reverse>(&values, i, j);
+        let exp_sum: i64 = values[i..=j].iter().map(|&v| v as i64).sum();
+
+        if tree.query_min(i, j) != exp_min
+            || tree.query_max(i, j) != exp_max
+            || tree.query_sum(i, j) != exp_sum
+            || tree.get(i) != values[i]
+        {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Verifies that a persistent segment tree creates the same results for each
+/// version as the given RMQ algorithm does for the data of that version.
+/// Creates versions by changing single entries of random earlier versions.
+/// Afterwards, randomly picks versions and index pairs and compares the
+/// resulting indices.
+pub fn verify_versions<T>(data_size: usize, queries: usize, seed: u64) -> bool
+where
+    T: Rmq<Number>,
+{
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_data(data_size, &mut rng);
+
+    // Generate algorithm.
+    let mut tree = PersistentSegmentTree::<Number>::new(data.clone());
+    tree.process_data();
+
+    // Create versions and keep a copy of the data of each.
+    let mut snapshots = vec![data];
+    for _ in 0..data_size.isqrt() {
+        let version = rng.gen_range(0..snapshots.len());
+        let idx = rng.gen_range(0..data_size);
+        let val = random_number(data_size, &mut rng);
+
+        let mut values = snapshots[version].to_vec();
+        values[idx] = val;
+
+        if tree.update(version, idx, val) != snapshots.len() {
+            return false;
+        }
+        snapshots.push(Rc::from(values.into_boxed_slice()));
+    }
+
+    // Generate reference algorithms.
+    let rmqs: Vec<T> = snapshots
+        .into_iter()
+        .map(|values| {
+            let mut rmq = T::new(values);
+            rmq.process_data();
+            rmq
+        })
+        .collect();
+
+    // Verify algorithms.
+    for _ in 0..queries {
+        let version = rng.gen_range(0..rmqs.len());
+        let (i, j) = random_index_pair(&mut rng, data_size);
+
+        let min1 = tree.query_version(version, i, j);
+        let min2 = rmqs[version].query(i, j);
+
+        if min1 != min2 {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Verifies that a sliding window creates the same results as the given RMQ
+/// algorithm.
+/// Randomly extends or shrinks the window and compares its minimum with the
+/// result for the range it covers. Afterwards, compares the minima of all
+/// windows of a random size.
+pub fn verify_sliding_window<T>(data_size: usize, queries: usize, seed: u64) -> bool
+where
+    T: Rmq<Number>,
+{
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_data(data_size, &mut rng);
+
+    // Generate and test algorithms.
+    let mut rmq = T::new(data.clone());
+    rmq.process_data();
+
+    let mut window = SlidingWindow::<Number>::new();
+
+    // Verify algorithms.
+    for _ in 0..queries {
+        let end = window.front() + window.len();
+
+        if window.is_empty() || (end < data_size && rng.gen_bool(0.5)) {
+            if end == data_size {
+                // Stream ended.
+                break;
+            }
+            window.push_back(data[end]);
+        } else {
+            let front = window.front();
+            if window.pop_front() != Some(front) {
+                return false;
+            }
+        }
+
+        if let Some((idx, &val)) = window.current_min() {
+            let end = window.front() + window.len();
+            if idx != rmq.query(window.front(), end - 1) || val != data[idx] {
+                return false;
+            }
+        }
+    }
+
+    // Popping from an empty window does not change it.
+    while window.pop_front().is_some() {}
+    if window.pop_front().is_some() || !window.is_empty() {
+        return false;
+    }
+
+    // Verify iterator.
+    let size = rng.gen_range(1..=data_size);
+    let mut count = 0;
+
+    for (i, min_idx) in window_minima(data.iter().copied(), size).enumerate() {
+        if min_idx != rmq.query(i, i + size - 1) {
+            return false;
+        }
+        count += 1;
+    }
+
+    count == data_size - size + 1
+}
+
+/// Verifies that an append-only RMQ creates the same results as the given RMQ
+/// algorithm while entries are appended.
+/// Starts with half of the data and randomly appends the remaining entries.
+/// After each step, picks an index pair among the entries appended so far and
+/// compares the resulting indices.
+pub fn verify_appends<T>(data_size: usize, queries: usize, seed: u64) -> bool
+where
+    T: Rmq<Number>,
+{
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_data(data_size, &mut rng);
+
+    // Generate and test algorithms.
+    let start = std::cmp::max(2, data_size / 2);
+    let mut rmq1 = AppendOnly::<Number>::new(Rc::from(&data[..start]));
+    let mut rmq2 = T::new(data.clone());
+
+    rmq1.process_data();
+    rmq2.process_data();
+
+    // Verify algorithms.
+    for _ in 0..queries {
+        if rmq1.len() < data_size && rng.gen_bool(0.5) {
+            rmq1.push(data[rmq1.len()]);
+        }
+
+        let (i, j) = random_index_pair(&mut rng, rmq1.len());
+
+        let min1 = rmq1.query(i, j);
+        let min2 = rmq2.query(i, j);
+
+        if min1 != min2 {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Verifies that an implicit treap creates correct results after changing the
+/// sequence.
+/// Randomly inserts or removes entries, or moves a prefix of the sequence to
+/// its end (by splitting and concatenating). After each change, picks index
+/// pairs and compares the result with the minimum determined by iterating over
+/// the range.
+pub fn verify_sequence_updates(data_size: usize, queries: usize, seed: u64) -> bool {
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_data(data_size, &mut rng);
+
+    // Generate and test algorithm.
+    let mut treap = ImplicitTreap::<Number>::with_seed(data.clone(), seed);
+    treap.process_data();
+
+    let mut values = data.to_vec();
+
+    // Verify algorithm.
+    for _ in 0..queries {
+        match rng.gen_range(0..3) {
+            0 => {
+                let pos = rng.gen_range(0..=values.len());
+                let val = random_number(data_size, &mut rng);
+
+                values.insert(pos, val);
+                treap.insert(pos, val);
+            }
+            1 if values.len() > 2 => {
+                let pos = rng.gen_range(0..values.len());
+
+                if treap.remove(pos) != values.remove(pos) {
+                    return false;
+                }
+            }
+            _ => {
+                let pos = rng.gen_range(0..=values.len());
+
+                values.rotate_left(pos);
+                let (left, mut right) = treap.split(pos);
+
+                // Pre-processing again does not change a sequence.
+                right.process_data();
+                treap = right.concat(left);
+            }
+        }
+
+        if treap.len() != values.len() {
+            return false;
+        }
+
+        let (i, j) = random_index_pair(&mut rng, values.len());
+
+        // The default order reports the right-most minimum.
+        let expected = rightmost_min::<_, Natural>(&values, i, j);
+
+        if treap.query(i, j) != expected || *treap.get(i) != values[i] {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Verifies that the 2D sparse table and 2D segment tree create correct
+/// results for a square matrix with (about) the given number of entries.
+/// Randomly picks rectangles and compares the results with the minimum
+/// determined by iterating over the rectangle. Afterwards, does the same for
+/// the segment tree while randomly changing entries.
+pub fn verify_rectangles(data_size: usize, queries: usize, seed: u64) -> bool {
+    let size = data_size.isqrt();
+
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_data(size * size, &mut rng);
+
+    // Generate and test algorithms.
+    let mut table = SparseTable2D::<Number>::new(data.clone(), size);
+    let mut tree = SegmentTree2D::<Number>::new(data.clone(), size);
+
+    table.process_data();
+    tree.process_data();
+
+    let mut values = data.to_vec();
+
+    // Verify algorithms.
+    for q in 0..(2 * queries) {
+        if q >= queries {
+            // Change an entry.
+            let (row, col) = (rng.gen_range(0..size), rng.gen_range(0..size));
+            let val = random_number(data_size, &mut rng);
+
+            values[row * size + col] = val;
+            tree.update(row, col, val);
+        }
+
+        let (r1, r2) = random_index_pair(&mut rng, size);
+        let (c1, c2) = random_index_pair(&mut rng, size);
+
+        // The default order reports the minimum that is last in row-major
+        // order, that is, the last of the right-most minima of all rows.
+        let row_mins: Vec<usize> = (r1..=r2)
+            .map(|r| r * size + rightmost_min::<_, Natural>(&values[(r * size)..], c1, c2))
+            .collect();
+        let row_vals: Vec<Number> = row_mins.iter().map(|&idx| values[idx]).collect();
+
+        let min = row_mins[rightmost_min::<_, Natural>(&row_vals, 0, r2 - r1)];
+        let expected = (min / size, min % size);
+
+        if tree.query(r1, r2, c1, c2) != expected {
+            return false;
+        }
+
+        if q < queries && table.query(r1, r2, c1, c2) != expected {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Verifies that the Cartesian tree of data with many equal entries is
+/// correct.
+/// Randomly picks index pairs and compares the lowest common ancestor in the
+/// tree with the result of the given RMQ algorithm. Also compares the nearest
+/// smaller values of random entries with those determined by iterating over
+/// the data.
+pub fn verify_cartesian_tree<T>(data_size: usize, queries: usize, seed: u64) -> bool
+where
+    T: Rmq<Number>,
+{
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_duplicates(data_size, &mut rng);
+
+    // Generate and test algorithms.
+    let ct = cartesian_tree(&data);
+
+    let mut lca = Lca::<SparseTable<usize>>::new(&ct.tree);
+    let mut rmq = T::new(data.clone());
+
+    lca.process_data();
+    rmq.process_data();
+
+    // Verify algorithms.
+    for _ in 0..queries {
+        let (i, j) = random_index_pair(&mut rng, data_size);
+
+        if lca.query(i, j) != rmq.query(i, j) {
+            return false;
+        }
+
+        // The default order prefers the right-most minimum. Hence, the
+        // previous entry has to be strictly smaller.
+        let idx = rng.gen_range(0..data_size);
+
+        let prev = (0..idx).rev().find(|&k| data[k] < data[idx]);
+        let next = ((idx + 1)..data_size).find(|&k| data[k] <= data[idx]);
+
+        if ct.prev_smaller[idx] != prev.unwrap_or(usize::MAX)
+            || ct.next_smaller[idx] != next.unwrap_or(usize::MAX)
+        {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Verifies that the k smallest entries determined with the given RMQ
+/// algorithm are correct.
+/// Randomly picks index pairs and numbers k, and compares the result with the
+/// k smallest entries determined by sorting the range.
+pub fn verify_top_k<T>(data_size: usize, queries: usize, seed: u64) -> bool
+where
+    T: Rmq<Number>,
+{
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_duplicates(data_size, &mut rng);
+
+    // Generate and test algorithm.
+    let mut rmq = T::new(data.clone());
+    rmq.process_data();
+
+    let max_k = 2 * log_f(data_size) + 1;
+
+    // Verify algorithm.
+    for _ in 0..queries {
+        let (i, j) = random_index_pair(&mut rng, data_size);
+        let k = rng.gen_range(1..=max_k);
+
+        // The default order reports the right-most minimum first.
+        let mut expected: Vec<usize> = (i..=j).collect();
+        let key = |&idx: &usize| (data[idx], Reverse(idx));
+
+        if k < expected.len() {
+            expected.select_nth_unstable_by_key(k - 1, key);
+            expected.truncate(k);
+        }
+        expected.sort_unstable_by_key(key);
+
+        if top_k(&rmq, &data, i, j, k) != expected {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Verifies that all minima determined with the given RMQ algorithm are
+/// correct.
+/// Randomly picks index pairs and compares the result with the minima
+/// determined by iterating over the range.
+pub fn verify_all_minima<T>(data_size: usize, queries: usize, seed: u64) -> bool
+where
+    T: Rmq<Number>,
+{
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_duplicates(data_size, &mut rng);
+
+    // Generate and test algorithm.
+    let mut rmq = T::new(data.clone());
+    rmq.process_data();
+
+    // Verify algorithm.
+    for _ in 0..queries {
+        let (i, j) = random_index_pair(&mut rng, data_size);
+
+        let min_val = *data[i..=j].iter().min().unwrap();
+        let expected: Vec<usize> = (i..=j).filter(|&idx| data[idx] == min_val).collect();
+
+        if all_min_positions(&rmq, &data, i, j) != expected {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Verifies that document listing with the given RMQ algorithm reports the
+/// distinct entries in a range.
+/// Randomly picks index pairs and compares the result with the first
+/// occurrences determined by iterating over the range.
+pub fn verify_document_listing<T>(data_size: usize, queries: usize, seed: u64) -> bool
+where
+    T: Rmq<usize>,
+{
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_duplicates(data_size, &mut rng);
+
+    // Generate and test algorithm.
+    let mut listing = DocumentListing::<Number, T>::new(data.clone());
+    listing.process_data();
+
+    // Verify algorithm.
+    for _ in 0..queries {
+        let (i, j) = random_index_pair(&mut rng, data_size);
+
+        let mut seen = HashSet::new();
+        let expected: Vec<usize> = (i..=j).filter(|&idx| seen.insert(data[idx])).collect();
+
+        if listing.distinct_in_range(i, j) != expected {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Verifies that the first and last entries below a threshold are found
+/// correctly by a segment tree and by a binary search with the given RMQ
+/// algorithm.
+/// Randomly picks index pairs and thresholds, and compares the results with
+/// the entries determined by iterating over the range.
+pub fn verify_threshold_search<T>(data_size: usize, queries: usize, seed: u64) -> bool
+where
+    T: Rmq<Number>,
+{
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_data(data_size, &mut rng);
+
+    // Generate and test algorithms.
+    let mut tree = SegmentTree::<Number>::new(data.clone());
+    let mut rmq = T::new(data.clone());
+
+    tree.process_data();
+    rmq.process_data();
+
+    // Verify algorithms.
+    for _ in 0..queries {
+        let (i, j) = random_index_pair(&mut rng, data_size);
+        let x = random_number(data_size, &mut rng);
+
+        let first = (i..=j).find(|&idx| data[idx] < x);
+        let last = (i..=j).rev().find(|&idx| data[idx] < x);
+
+        if tree.find_first_below(i, j, &x) != first
+            || tree.find_last_below(i, j, &x) != last
+            || find_first_below(&rmq, &data, i, j, &x) != first
+            || find_last_below(&rmq, &data, i, j, &x) != last
+        {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Verifies that a sparse table and a segment tree create correct results
+/// for the two smallest entries of a range, and for the smallest entry when
+/// ignoring one index.
+/// Randomly picks index pairs and compares the results with those determined
+/// by iterating over the range.
+pub fn verify_second_minimum(data_size: usize, queries: usize, seed: u64) -> bool {
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_duplicates(data_size, &mut rng);
+
+    // Generate and test algorithms.
+    let mut table = SparseTable::<Number, MinTwo>::new(data.clone());
+    let mut tree = SegmentTree::<Number, MinTwo>::new(data.clone());
+
+    table.process_data();
+    tree.process_data();
+
+    // The minimum of the given indices. The default order reports the
+    // right-most one.
+    let min_of = |indices: &mut dyn Iterator<Item = usize>| {
+        indices.reduce(|min, idx| if data[idx] <= data[min] { idx } else { min })
+    };
+
+    // Verify algorithms.
+    for _ in 0..queries {
+        let (i, j) = random_index_pair(&mut rng, data_size);
+
+        let first = min_of(&mut (i..=j)).unwrap();
+        let second = min_of(&mut (i..=j).filter(|&idx| idx != first));
+
+        if table.fold(i, j) != (first, second) || tree.fold(i, j) != (first, second) {
+            return false;
+        }
+
+        let excl = rng.gen_range(i..=j);
+        let expected = min_of(&mut (i..=j).filter(|&idx| idx != excl));
+
+        if table.query_excluding(i, j, excl) != expected
+            || tree.query_excluding(i, j, excl) != expected
+        {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Verifies that circular queries with the given RMQ algorithm create correct
+/// results on data with many equal entries.
+/// Randomly picks index pairs, which wrap around if i > j, and compares the
+/// result with the minimum determined by iterating over the range.
+pub fn verify_circular<T>(data_size: usize, queries: usize, seed: u64) -> bool
+where
     T: Rmq<Number>,
 {
     // Generate random numbers.
     let mut rng = StdRng::seed_from_u64(seed);
-    let data = generate_data(data_size, &mut rng);
+    let data = generate_duplicates(data_size, &mut rng);
+
+    verify_circular_inner::<T, _>(data, rng, data_size, queries)
+}
+
+/// Verifies that circular queries with a +-1 RMQ create correct results.
+/// See `verify_circular()`.
+pub fn verify_circular_plus_minus(data_size: usize, queries: usize, seed: u64) -> bool {
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_plus_minus(data_size, &mut rng);
+
+    verify_circular_inner::<PlusMinus<Number>, _>(data, rng, data_size, queries)
+}
+
+/// A record to verify orders that compare a field or several fields.
+#[derive(Clone, Copy)]
+pub struct Record {
+    pub key: Number,
+    pub tie: Number,
+}
+
+/// Extracts the key of a record.
+pub struct RecordKey;
+
+impl Key<Record> for RecordKey {
+    type Key = Number;
+
+    fn key(value: &Record) -> Number {
+        value.key
+    }
+}
+
+/// Extracts the second key of a record.
+pub struct RecordTie;
+
+impl Key<Record> for RecordTie {
+    type Key = Number;
+
+    fn key(value: &Record) -> Number {
+        value.tie
+    }
+}
+
+/// Verifies that RMQ algorithms over records create correct results. `S` has
+/// to order records by `RecordKey`, and `T` lexicographically by `RecordKey`
+/// and `RecordTie`.
+/// Randomly picks index pairs and compares the results with the minima
+/// determined by iterating over the range.
+pub fn verify_key_orders<S, T>(data_size: usize, queries: usize, seed: u64) -> bool
+where
+    S: Rmq<Record>,
+    T: Rmq<Record>,
+{
+    // Generate random records with many equal keys.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let keys = generate_duplicates(data_size, &mut rng);
+    let ties = generate_duplicates(data_size, &mut rng);
+
+    let data: Rc<[Record]> = keys
+        .iter()
+        .zip(ties.iter())
+        .map(|(&key, &tie)| Record { key, tie })
+        .collect();
 
     // Generate and test algorithms.
     let mut rmq1 = S::new(data.clone());
@@ -34,14 +788,229 @@ where
     rmq1.process_data();
     rmq2.process_data();
 
+    // Tuples are ordered lexicographically.
+    let pairs: Vec<(Number, Number)> = data.iter().map(|rec| (rec.key, rec.tie)).collect();
+
     // Verify algorithms.
     for _ in 0..queries {
         let (i, j) = random_index_pair(&mut rng, data_size);
 
-        let min1 = rmq1.query(i, j);
-        let min2 = rmq2.query(i, j);
+        // The default order reports the right-most minimum.
+        let exp_key = rightmost_min::<_, Natural>(&keys, i, j);
+        let exp_lex = rightmost_min::<_, Natural>(&pairs, i, j);
+
+        if rmq1.query(i, j) != exp_key || rmq2.query(i, j) != exp_lex {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Verifies that the offline algorithm creates the same results as the given
+/// RMQ algorithm for a batch of random index pairs.
+pub fn verify_offline<T>(data_size: usize, queries: usize, seed: u64) -> bool
+where
+    T: Rmq<Number>,
+{
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_data(data_size, &mut rng);
+
+    verify_offline_inner::<T, Natural, _>(data, rng, data_size, queries)
+}
+
+/// Verifies that the offline algorithm with the given order creates the same
+/// results as the given RMQ algorithm on data with many equal entries. That is,
+/// it verifies that both use the same tie-breaking policy.
+pub fn verify_offline_ties<T, O>(data_size: usize, queries: usize, seed: u64) -> bool
+where
+    T: Rmq<Number>,
+    O: Order<Number>,
+{
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_duplicates(data_size, &mut rng);
+
+    verify_offline_inner::<T, O, _>(data, rng, data_size, queries)
+}
+
+/// Verifies that the offline algorithm with the given order creates the same
+/// results as the given RMQ algorithm on the given data.
+fn verify_offline_inner<T, O, R>(
+    data: Rc<[Number]>,
+    mut rng: R,
+    data_size: usize,
+    queries: usize,
+) -> bool
+where
+    T: Rmq<Number>,
+    O: Order<Number>,
+    R: Rng,
+{
+    // Generate queries.
+    let mut pairs = Vec::with_capacity(queries);
+    pairs.resize_with(queries, || random_index_pair(&mut rng, data_size));
+
+    // Generate and test algorithms.
+    let mut rmq = T::new(data.clone());
+    rmq.process_data();
+
+    let result = offline_rmq_by::<Number, O>(&data, &pairs);
+
+    // Verify algorithms.
+    for (q, &(i, j)) in pairs.iter().enumerate() {
+        let min1 = result[q];
+        let min2 = rmq.query(i, j);
+
+        if min1 != min2 {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// A non-commutative operation to verify range queries.
+/// Determines the first entry, the last entry, and the sum of a range.
+struct FirstLastSum;
+
+impl Semigroup<Number> for FirstLastSum {
+    type Output = (Number, Number, i64);
+
+    fn lift(data: &[Number], idx: usize) -> Self::Output {
+        (data[idx], data[idx], data[idx] as i64)
+    }
+
+    fn combine(_: &[Number], a: &Self::Output, b: &Self::Output) -> Self::Output {
+        (a.0, b.1, a.2 + b.2)
+    }
+}
+
+/// Verifies that a disjoint sparse table creates correct results.
+/// Randomly picks index pairs and compares the result with the one determined
+/// by iterating over the range.
+pub fn verify_disjoint_sparse_table(data_size: usize, queries: usize, seed: u64) -> bool {
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_data(data_size, &mut rng);
+
+    // Generate and test algorithm.
+    let mut table = DisjointSparseTable::<Number, FirstLastSum>::new(data.clone());
+    table.process_data();
+
+    // Verify algorithm.
+    for _ in 0..queries {
+        let (i, j) = random_index_pair(&mut rng, data_size);
+
+        if table.fold(i, j) != fold_range::<FirstLastSum>(&data, i, j) {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Verifies that a sparse table creates correct results for the given
+/// operation.
+/// Randomly picks index pairs and compares the result with the one determined
+/// by iterating over the range.
+pub fn verify_sparse_table_fold<Op>(data_size: usize, queries: usize, seed: u64) -> bool
+where
+    Op: Idempotent<Number>,
+    Op::Output: PartialEq,
+{
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_data(data_size, &mut rng);
+
+    // Generate and test algorithm.
+    let mut table = SparseTable::<Number, Op>::new(data.clone());
+    table.process_data();
+
+    // Verify algorithm.
+    for _ in 0..queries {
+        let (i, j) = random_index_pair(&mut rng, data_size);
+
+        if table.fold(i, j) != fold_range::<Op>(&data, i, j) {
+            return false;
+        }
+    }
 
-        if data[min1] != data[min2] {
+    true
+}
+
+/// Verifies that sparse tables create correct results for the greatest common
+/// divisor and least common multiple.
+/// Uses small entries, about half of them negative, such that multiples do not
+/// overflow. Randomly picks index pairs and compares the results with those
+/// determined by iterating over the range.
+pub fn verify_gcd_lcm(data_size: usize, queries: usize, seed: u64) -> bool {
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let max_val = (log_f(data_size) + 1) as Number;
+
+    let mut data = Vec::with_capacity(data_size);
+    data.resize_with(data_size, || rng.gen_range(-max_val..=max_val));
+    let data: Rc<[Number]> = Rc::from(data.into_boxed_slice());
+
+    // Generate and test algorithms.
+    let mut gcd_table = SparseTable::<Number, Gcd>::new(data.clone());
+    let mut lcm_table = SparseTable::<Number, Lcm>::new(data.clone());
+
+    gcd_table.process_data();
+    lcm_table.process_data();
+
+    let gcd = |mut a: Number, mut b: Number| {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    };
+
+    // Verify algorithms.
+    for _ in 0..queries {
+        let (i, j) = random_index_pair(&mut rng, data_size);
+
+        let mut exp_gcd = 0;
+        let mut exp_lcm = 1;
+        for &val in &data[i..=j] {
+            let val = val.abs();
+
+            exp_gcd = gcd(exp_gcd, val);
+            exp_lcm = if val == 0 {
+                0
+            } else {
+                exp_lcm / gcd(exp_lcm, val) * val
+            };
+        }
+
+        if gcd_table.fold(i, j) != exp_gcd || lcm_table.fold(i, j) != exp_lcm {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Verifies that a segment tree creates correct results for operations that
+/// are neither commutative nor idempotent.
+/// Randomly picks index pairs and compares the result with the one determined
+/// by iterating over the range.
+pub fn verify_segment_tree_fold(data_size: usize, queries: usize, seed: u64) -> bool {
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_data(data_size, &mut rng);
+
+    // Generate and test algorithm.
+    let mut tree = SegmentTree::<Number, FirstLastSum>::new(data.clone());
+    tree.process_data();
+
+    // Verify algorithm.
+    for _ in 0..queries {
+        let (i, j) = random_index_pair(&mut rng, data_size);
+
+        if tree.fold(i, j) != fold_range::<FirstLastSum>(&data, i, j) {
             return false;
         }
     }
@@ -49,6 +1018,29 @@ where
     true
 }
 
+/// Determines the index of the right-most minimum in the given range with
+/// respect to the given order by iterating over it.
+fn rightmost_min<T, O: Order<T>>(values: &[T], i: usize, j: usize) -> usize {
+    let mut res = i;
+    for idx in (i + 1)..=j {
+        if !O::less(&values[res], &values[idx]) {
+            res = idx;
+        }
+    }
+
+    res
+}
+
+/// Combines all entries in the given range by iterating over it.
+fn fold_range<Op: Semigroup<Number>>(data: &[Number], i: usize, j: usize) -> Op::Output {
+    let mut res = Op::lift(data, i);
+    for idx in (i + 1)..=j {
+        res = Op::combine(data, &res, &Op::lift(data, idx));
+    }
+
+    res
+}
+
 /// Determines the runtime of the given algorithm.
 /// Returns the runtime for preprocessing and for queries.
 pub fn get_runtime<T: Rmq<Number>>(data_size: usize, queries: usize, seed: u64) -> TimePair {
@@ -74,7 +1066,7 @@ where
     let data = generate_plus_minus(data_size, &mut rng);
 
     // Generate and test algorithms.
-    let mut rmq1 = PlusMinus::new(data.clone());
+    let mut rmq1 = PlusMinus::<Number>::new(data.clone());
     let mut rmq2 = T::new(data.clone());
 
     rmq1.process_data();
@@ -87,7 +1079,7 @@ where
         let min1 = rmq1.query(i, j);
         let min2 = rmq2.query(i, j);
 
-        if data[min1] != data[min2] {
+        if min1 != min2 {
             return false;
         }
     }
@@ -95,6 +1087,22 @@ where
     true
 }
 
+/// Verifies that two RMQ algorithms create the same result on data with the
+/// +-1 property, which has many equal entries. That is, it verifies that both
+/// use the same tie-breaking policy.
+/// Randomly picks index pairs and compares the resulting indices.
+pub fn verify_plus_minus_ties<S, T>(data_size: usize, queries: usize, seed: u64) -> bool
+where
+    S: Rmq<Number>,
+    T: Rmq<Number>,
+{
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_plus_minus(data_size, &mut rng);
+
+    verify_algorithms_inner::<S, T, _>(data, rng, data_size, queries)
+}
+
 /// Determines the runtime of the given algorithm.
 /// Returns the runtime for preprocessing and for queries.
 pub fn get_plus_minus_runtime(data_size: usize, queries: usize, seed: u64) -> TimePair {
@@ -105,48 +1113,82 @@ pub fn get_plus_minus_runtime(data_size: usize, queries: usize, seed: u64) -> Ti
     let data = generate_plus_minus(data_size, &mut rng);
 
     // Run test.
-    let rmq = PlusMinus::new(data);
+    let rmq = PlusMinus::<Number>::new(data);
     get_runtime_inner(rmq, rng, data_size, queries)
 }
 
+/// Determines the runtime of the offline algorithm.
+/// Since it has no separate pre-processing, the whole runtime is reported as
+/// runtime for queries.
+pub fn get_offline_runtime(data_size: usize, queries: usize, seed: u64) -> TimePair {
+    // Generate random numbers.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let data = generate_data(data_size, &mut rng);
+
+    // Generate queries.
+    let mut pairs = Vec::with_capacity(queries);
+    pairs.resize_with(queries, || random_index_pair(&mut rng, data_size));
+
+    // Queries
+    let start = Instant::now();
+    offline_rmq(&data, &pairs);
+    let q_time = start.elapsed();
+
+    (0, q_time.as_millis() as i64)
+}
+
 /// Measures the time needed to preprocess and to run queries using the
 /// given RMQ algorithm.
 pub fn get_ancestor_runtime<T>(tree_size: usize, queries: usize, seed: u64) -> TimePair
 where
     T: Rmq<usize>,
 {
-    // ToDo: Implement LCA.
-    todo!();
-
-    // // Generate a random tree.
-    // let mut rng = StdRng::seed_from_u64(seed);
-    // let tree = generate_tree(tree_size, &mut rng);
+    // Generate a random tree.
+    let mut rng = StdRng::seed_from_u64(seed);
+    let tree = generate_tree(tree_size, &mut rng);
 
-    // let lca = Lca::new(tree);
+    let mut lca = Lca::<T>::new(&tree);
 
-    // // Preprocessing
-    // let start = Instant::now();
-    // lca.process_data();
-    // let p_time = start.elapsed();
+    // Preprocessing
+    let start = Instant::now();
+    lca.process_data();
+    let p_time = start.elapsed();
 
-    // // Queries
-    // let start = Instant::now();
-    // for _ in 0..queries {
-    //     let (u_id, v_id) = random_index_pair(&mut rng, tree_size);
-    //     lca.query(u_id, v_id);
-    // }
-    // let q_time = start.elapsed();
+    // Queries
+    let start = Instant::now();
+    for _ in 0..queries {
+        let (u_id, v_id) = random_index_pair(&mut rng, tree_size);
+        lca.query(u_id, v_id);
+    }
+    let q_time = start.elapsed();
 
-    // (p_time, q_time)
+    (p_time.as_millis() as i64, q_time.as_millis() as i64)
 }
 
 /// Generates a list of random numbers with the given size.
 fn generate_data<R: Rng>(size: usize, rng: &mut R) -> Rc<[Number]> {
+    let mut data = Vec::with_capacity(size);
+    data.resize_with(size, || random_number(size, rng));
+
+    Rc::from(data.into_boxed_slice())
+}
+
+/// Generates a random number suitable for a list with the given size.
+#[inline]
+fn random_number<R: Rng>(size: usize, rng: &mut R) -> Number {
     let max_val = (size * log_f(size)) as Number;
     let shift = max_val >> 2;
 
+    rng.gen_range(0..max_val) - shift
+}
+
+/// Generates a list of random numbers with the given size that contains many
+/// equal entries.
+fn generate_duplicates<R: Rng>(size: usize, rng: &mut R) -> Rc<[Number]> {
+    let max_val = (log_f(size) + 1) as Number;
+
     let mut data = Vec::with_capacity(size);
-    data.resize_with(size, || rng.gen_range(0..max_val) - shift);
+    data.resize_with(size, || rng.gen_range(0..max_val));
 
     Rc::from(data.into_boxed_slice())
 }
@@ -213,6 +1255,78 @@ fn random_index_pair<R: Rng>(rng: &mut R, max_index: usize) -> (usize, usize) {
     (i, j)
 }
 
+/// Verifies that two RMQ algorithm create the same result on the given data.
+fn verify_algorithms_inner<S, T, R>(
+    data: Rc<[Number]>,
+    mut rng: R,
+    data_size: usize,
+    queries: usize,
+) -> bool
+where
+    S: Rmq<Number>,
+    T: Rmq<Number>,
+    R: Rng,
+{
+    // Generate and test algorithms.
+    let mut rmq1 = S::new(data.clone());
+    let mut rmq2 = T::new(data.clone());
+
+    rmq1.process_data();
+    rmq2.process_data();
+
+    // Verify algorithms.
+    for _ in 0..queries {
+        let (i, j) = random_index_pair(&mut rng, data_size);
+
+        let min1 = rmq1.query(i, j);
+        let min2 = rmq2.query(i, j);
+
+        if min1 != min2 {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Verifies that circular queries with the given RMQ algorithm create correct
+/// results on the given data.
+fn verify_circular_inner<T, R>(
+    data: Rc<[Number]>,
+    mut rng: R,
+    data_size: usize,
+    queries: usize,
+) -> bool
+where
+    T: Rmq<Number>,
+    R: Rng,
+{
+    // Generate and test algorithm.
+    let mut rmq = T::new(data.clone());
+    rmq.process_data();
+
+    // Each circular range is a range of the data repeated twice.
+    let doubled: Vec<Number> = data.iter().chain(data.iter()).copied().collect();
+
+    // Verify algorithm.
+    for _ in 0..queries {
+        let (mut i, mut j) = random_index_pair(&mut rng, data_size);
+        if rng.gen_bool(0.5) {
+            swap(&mut i, &mut j);
+        }
+
+        // The default order reports the last minimum in the range.
+        let len = (j + data_size - i) % data_size + 1;
+        let expected = rightmost_min::<_, Natural>(&doubled, i, i + len - 1) % data_size;
+
+        if circular_query(&rmq, &data, i, j) != expected {
+            return false;
+        }
+    }
+
+    true
+}
+
 /// Measures the time needed to preprocess and to run queries using the
 /// given RMQ algorithm.
 fn get_runtime_inner<R, T>(mut rmq: T, mut rng: R, data_size: usize, queries: usize) -> TimePair
@@ -6,7 +6,7 @@ use crate::rmq::Rmq;
 pub type NodeId = usize;
 
 /// Represents a null pointer.
-const NULL_NODE: NodeId = NodeId::MAX;
+pub const NULL_NODE: NodeId = NodeId::MAX;
 
 /// Represents an [Euler tour](https://en.wikipedia.org/wiki/Euler_tour_technique) of a tree.
 pub struct EulerTour {
@@ -32,6 +32,12 @@ pub struct Tree {
     children: Vec<Vec<NodeId>>,
 }
 
+impl Default for Tree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Tree {
     /// Creates an empty tree.
     pub fn new() -> Self {